@@ -1,10 +1,11 @@
 fn main() {
     protobuf_codegen::Codegen::new()
         .out_dir("src/protocol")
-        .inputs(&[
+        .inputs([
             "proto/keyexchange.proto",
             "proto/authentication.proto",
             "proto/mercury.proto",
+            "proto/metadata.proto",
         ])
         .include("proto")
         .run()
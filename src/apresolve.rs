@@ -0,0 +1,240 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use log::{debug, warn};
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::proxy::Proxy;
+
+const APRESOLVE_URL: &str = "https://apresolve.spotify.com/?type=accesspoint&type=dealer&type=spclient";
+
+// 4070 is the native AP port, 443 and 80 get through most firewalls
+pub const PREFERRED_PORTS: [u16; 3] = [4070, 443, 80];
+
+const FALLBACK_ACCESSPOINTS: [&str; 3] = [
+    "ap.spotify.com:4070",
+    "ap.spotify.com:443",
+    "ap.spotify.com:80",
+];
+
+#[derive(Debug, Error)]
+pub enum ApResolveError {
+    #[error("request failed: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("invalid response: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("invalid accesspoint address {0:?}")]
+    InvalidAddress(String),
+    #[error("empty accesspoint list")]
+    Empty,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccessPoint {
+    pub host: String,
+    pub port: u16,
+}
+
+impl FromStr for AccessPoint {
+    type Err = ApResolveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ApResolveError::InvalidAddress(s.to_owned());
+
+        let (host, port) = match s.strip_prefix('[') {
+            // [2001:db8::1]:4070
+            Some(rest) => {
+                let (host, port) = rest.split_once("]:").ok_or_else(invalid)?;
+                (host, port)
+            }
+            None => {
+                let (host, port) = s.rsplit_once(':').ok_or_else(invalid)?;
+                // an IPv6 literal needs brackets, otherwise its last group reads as the port
+                if host.contains(':') {
+                    return Err(invalid());
+                }
+                (host, port)
+            }
+        };
+        if host.is_empty() {
+            return Err(invalid());
+        }
+        let port = port.parse().map_err(|_| invalid())?;
+
+        Ok(AccessPoint {
+            host: host.to_owned(),
+            port,
+        })
+    }
+}
+
+impl fmt::Display for AccessPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.host.contains(':') {
+            write!(f, "[{}]:{}", self.host, self.port)
+        } else {
+            write!(f, "{}:{}", self.host, self.port)
+        }
+    }
+}
+
+#[derive(Deserialize, Default, Debug)]
+pub struct ApResolveData {
+    pub accesspoint: Vec<String>,
+    // pub dealer: Vec<String>,
+    // pub spclient: Vec<String>,
+}
+
+impl ApResolveData {
+    pub fn accesspoints(&self) -> impl Iterator<Item = AccessPoint> {
+        self.accesspoint.iter().filter_map(|s| match s.parse() {
+            Ok(ap) => Some(ap),
+            Err(e) => {
+                warn!("skipping accesspoint: {e}");
+                None
+            }
+        })
+    }
+
+    // preferred ports first, the server order is kept within a port
+    pub fn sorted_accesspoints(&self) -> Vec<AccessPoint> {
+        let mut accesspoints: Vec<_> = self.accesspoints().collect();
+        accesspoints.sort_by_key(|ap| port_rank(ap.port));
+        accesspoints
+    }
+}
+
+fn port_rank(port: u16) -> usize {
+    PREFERRED_PORTS
+        .iter()
+        .position(|p| *p == port)
+        .unwrap_or(PREFERRED_PORTS.len())
+}
+
+pub fn fallback_accesspoints() -> Vec<AccessPoint> {
+    FALLBACK_ACCESSPOINTS
+        .iter()
+        .map(|s| s.parse().expect("valid fallback accesspoint"))
+        .collect()
+}
+
+pub async fn reqwest_ap_resolve_data() -> Result<ApResolveData, ApResolveError> {
+    reqwest_ap_resolve_data_with(&reqwest::Client::new()).await
+}
+
+pub async fn reqwest_ap_resolve_data_with(client: &reqwest::Client) -> Result<ApResolveData, ApResolveError> {
+    let body = client.get(APRESOLVE_URL).send().await?.bytes().await?;
+    Ok(serde_json::from_slice(&body)?)
+}
+
+#[derive(Debug, Clone)]
+pub struct ApResolver {
+    pub retries: u32,
+    pub backoff: Duration,
+    // per attempt, so a network that drops packets falls back quickly
+    pub connect_timeout: Duration,
+    pub timeout: Duration,
+}
+
+impl Default for ApResolver {
+    fn default() -> Self {
+        ApResolver {
+            retries: 3,
+            backoff: Duration::from_millis(500),
+            connect_timeout: Duration::from_secs(5),
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+impl ApResolver {
+    pub async fn resolve_data(&self, proxy: Option<&Proxy>) -> Result<ApResolveData, ApResolveError> {
+        let mut client = reqwest::Client::builder()
+            .connect_timeout(self.connect_timeout)
+            .timeout(self.timeout);
+        if let Some(proxy) = proxy {
+            client = client.proxy(proxy.reqwest_proxy()?);
+        }
+        let client = client.build()?;
+
+        let mut backoff = self.backoff;
+        let mut attempt = 0;
+        loop {
+            match reqwest_ap_resolve_data_with(&client).await {
+                Ok(data) => return Ok(data),
+                Err(e) if attempt < self.retries => {
+                    debug!("apresolve failed ({e}), retrying in {backoff:?}");
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    // never empty: falls back to the built-in list when apresolve is unreachable
+    pub async fn resolve(&self, proxy: Option<&Proxy>) -> Vec<AccessPoint> {
+        let accesspoints = match self.resolve_data(proxy).await {
+            Ok(data) => data.sorted_accesspoints(),
+            Err(e) => {
+                warn!("apresolve unavailable: {e}");
+                Vec::new()
+            }
+        };
+
+        if accesspoints.is_empty() {
+            warn!("using fallback accesspoints");
+            return fallback_accesspoints();
+        }
+        accesspoints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ap(host: &str, port: u16) -> AccessPoint {
+        AccessPoint {
+            host: host.to_owned(),
+            port,
+        }
+    }
+
+    #[test]
+    fn parse_accesspoints() {
+        assert_eq!("ap.spotify.com:4070".parse::<AccessPoint>().unwrap(), ap("ap.spotify.com", 4070));
+        assert_eq!("[2001:db8::1]:4070".parse::<AccessPoint>().unwrap(), ap("2001:db8::1", 4070));
+        assert_eq!(ap("2001:db8::1", 443).to_string(), "[2001:db8::1]:443");
+
+        let invalid = [
+            "ap.spotify.com",
+            ":4070",
+            "[]:4070",
+            "[2001:db8::1]",
+            "2001:db8::1",
+            "ap.spotify.com:http",
+            "ap.spotify.com:70000",
+        ];
+        for invalid in invalid {
+            assert!(
+                matches!(invalid.parse::<AccessPoint>(), Err(ApResolveError::InvalidAddress(_))),
+                "{invalid} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn preferred_ports_first() {
+        let data = ApResolveData {
+            accesspoint: ["a:80", "b:8080", "c:443", "bad", "d:4070", "e:443"]
+                .map(str::to_owned)
+                .to_vec(),
+        };
+        let expected = [ap("d", 4070), ap("c", 443), ap("e", 443), ap("a", 80), ap("b", 8080)];
+        assert_eq!(data.sorted_accesspoints(), expected);
+    }
+}
@@ -0,0 +1,76 @@
+use std::io::{self, SeekFrom};
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+
+use aes::Aes128;
+use ctr::Ctr128BE;
+use ctr::cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
+use tokio::io::{AsyncRead, AsyncSeek, AsyncSeekExt, ReadBuf};
+
+use crate::audio_key::AudioKey;
+
+const AUDIO_AES_IV: [u8; 16] = [
+    0x72, 0xe0, 0x67, 0xfb, 0xdd, 0xcb, 0xcf, 0x77, 0xeb, 0xe8, 0xbc, 0x64, 0x3f, 0x63, 0x0d, 0x93,
+];
+
+// the decrypted file starts with a Spotify specific header, Ogg data follows
+pub const SPOTIFY_HEADER_SIZE: u64 = 0xa7;
+
+// Decrypts an AES-128-CTR encrypted audio file on the fly. Positions are
+// relative to the end of the Spotify header, so position 0 is the first
+// byte of Ogg data.
+pub struct AudioDecrypt<R> {
+    inner: R,
+    cipher: Ctr128BE<Aes128>,
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> AudioDecrypt<R> {
+    pub async fn new(key: AudioKey, mut inner: R) -> io::Result<AudioDecrypt<R>> {
+        let mut cipher = Ctr128BE::<Aes128>::new(&key.0.into(), &AUDIO_AES_IV.into());
+        inner.seek(SeekFrom::Start(SPOTIFY_HEADER_SIZE)).await?;
+        cipher.seek(SPOTIFY_HEADER_SIZE);
+        Ok(AudioDecrypt { inner, cipher })
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for AudioDecrypt<R> {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let offset = buf.filled().len();
+        ready!(Pin::new(&mut self.inner).poll_read(cx, buf))?;
+        self.cipher.apply_keystream(&mut buf.filled_mut()[offset..]);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<R: AsyncSeek + Unpin> AsyncSeek for AudioDecrypt<R> {
+    fn start_seek(mut self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        let position = match position {
+            SeekFrom::Start(pos) => {
+                let pos = pos.checked_add(SPOTIFY_HEADER_SIZE).ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidInput, "seek position out of range")
+                })?;
+                SeekFrom::Start(pos)
+            }
+            relative => relative,
+        };
+        Pin::new(&mut self.inner).start_seek(position)
+    }
+
+    fn poll_complete(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        let pos = ready!(Pin::new(&mut self.inner).poll_complete(cx))?;
+        // CTR keystream position is the byte offset in the encrypted file,
+        // keep it in step with the reader even when the seek is refused
+        self.cipher.seek(pos);
+        if pos < SPOTIFY_HEADER_SIZE {
+            return Poll::Ready(Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "seek into the Spotify header",
+            )));
+        }
+        Poll::Ready(Ok(pos - SPOTIFY_HEADER_SIZE))
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bytes::{Buf, BufMut, Bytes};
+use log::{debug, trace};
+use thiserror::Error;
+use tokio::sync::oneshot;
+
+use crate::consts::PacketType;
+use crate::dispatch::{PacketHandler, PacketSender};
+use crate::error::{Error, Result};
+use crate::spotify_id::{FileId, SpotifyId};
+
+pub const DEFAULT_AUDIO_KEY_TIMEOUT: Duration = Duration::from_secs(15);
+
+const AUDIO_KEY_SIZE: usize = 16;
+
+// RequestKey: FILE_ID 20 bytes, TRACK_GID 16 bytes, SEQ u32, 0x0000
+// AesKey:      SEQ u32, KEY 16 bytes
+// AesKeyError: SEQ u32, CODE u16
+
+#[derive(Debug, Error)]
+pub enum AudioKeyError {
+    #[error("access point refused the audio key with code {0:#06x}")]
+    Remote(u16),
+    #[error("audio key request timed out")]
+    Timeout,
+    #[error("malformed audio key reply")]
+    Malformed,
+    #[error("audio key request was cancelled")]
+    Cancelled,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct AudioKey(pub [u8; AUDIO_KEY_SIZE]);
+
+// keys are secrets, keep them out of logs
+impl fmt::Debug for AudioKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AudioKey(..)")
+    }
+}
+
+#[derive(Default)]
+struct AudioKeyState {
+    sequence: u32,
+    pending: HashMap<u32, oneshot::Sender<Result<AudioKey>>>,
+}
+
+struct AudioKeyInner {
+    sender: PacketSender,
+    state: Mutex<AudioKeyState>,
+}
+
+#[derive(Clone)]
+pub struct AudioKeyManager(Arc<AudioKeyInner>);
+
+impl AudioKeyManager {
+    pub fn new(sender: PacketSender) -> AudioKeyManager {
+        AudioKeyManager(Arc::new(AudioKeyInner {
+            sender,
+            state: Mutex::new(AudioKeyState::default()),
+        }))
+    }
+
+    pub async fn request(&self, track: &SpotifyId, file: FileId) -> Result<AudioKey> {
+        self.request_with_timeout(track, file, DEFAULT_AUDIO_KEY_TIMEOUT).await
+    }
+
+    pub async fn request_with_timeout(
+        &self,
+        track: &SpotifyId,
+        file: FileId,
+        timeout: Duration,
+    ) -> Result<AudioKey> {
+        let (tx, rx) = oneshot::channel();
+        let seq = {
+            let mut state = self.0.state.lock().unwrap();
+            let seq = state.sequence;
+            state.sequence = state.sequence.wrapping_add(1);
+            state.pending.insert(seq, tx);
+            seq
+        };
+        // dropped on every exit path, including cancellation of this future
+        let _pending = PendingGuard { manager: self, seq };
+
+        let mut data = Vec::with_capacity(20 + 16 + 4 + 2);
+        data.put_slice(&file.0);
+        data.put_slice(&track.to_raw());
+        data.put_u32(seq);
+        data.put_u16(0);
+
+        debug!("requesting audio key for {file} (seq {seq})");
+        self.0.sender.send(PacketType::RequestKey, data)?;
+
+        match tokio::time::timeout(timeout, rx).await {
+            Ok(Ok(reply)) => reply,
+            Ok(Err(_)) => Err(AudioKeyError::Cancelled.into()),
+            Err(_) => Err(AudioKeyError::Timeout.into()),
+        }
+    }
+
+    fn dispatch(&self, cmd: PacketType, mut data: Bytes) -> Result<()> {
+        if data.len() < 4 {
+            return Err(AudioKeyError::Malformed.into());
+        }
+        let seq = data.get_u32();
+
+        let reply = match cmd {
+            PacketType::AesKey if data.len() >= AUDIO_KEY_SIZE => {
+                let mut key = [0u8; AUDIO_KEY_SIZE];
+                data.copy_to_slice(&mut key);
+                Ok(AudioKey(key))
+            }
+            PacketType::AesKeyError if data.len() >= 2 => Err(AudioKeyError::Remote(data.get_u16()).into()),
+            _ => Err(AudioKeyError::Malformed.into()),
+        };
+
+        match self.0.state.lock().unwrap().pending.remove(&seq) {
+            Some(tx) => {
+                let _ = tx.send(reply);
+            }
+            None => trace!("ignoring {cmd:?} for unknown sequence {seq}"),
+        }
+        Ok(())
+    }
+}
+
+struct PendingGuard<'a> {
+    manager: &'a AudioKeyManager,
+    seq: u32,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        self.manager.0.state.lock().unwrap().pending.remove(&self.seq);
+    }
+}
+
+impl PacketHandler for AudioKeyManager {
+    fn handle(&self, cmd: PacketType, data: Bytes) -> Result<()> {
+        self.dispatch(cmd, data)
+    }
+
+    fn close(&self) {
+        let pending = std::mem::take(&mut self.0.state.lock().unwrap().pending);
+        for tx in pending.into_values() {
+            let _ = tx.send(Err(Error::SessionClosed));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dispatch::Dispatcher;
+    use crate::spotify_id::SpotifyItemType;
+
+    fn track() -> SpotifyId {
+        SpotifyId::new(SpotifyItemType::Track, 238762092608182713602505436543891614649)
+    }
+
+    // sends a request and returns it together with the sequence it went out with
+    async fn start_request(
+        dispatcher: &mut Dispatcher,
+        manager: &AudioKeyManager,
+        file: FileId,
+    ) -> (tokio::task::JoinHandle<Result<AudioKey>>, [u8; 4]) {
+        let request = tokio::spawn({
+            let manager = manager.clone();
+            async move { manager.request(&track(), file).await }
+        });
+        let sent = dispatcher.next_outgoing().await.unwrap();
+        assert_eq!(sent.kind(), PacketType::RequestKey);
+        assert_eq!(sent.payload[..20], file.0);
+        assert_eq!(sent.payload[20..36], track().to_raw());
+        (request, sent.payload[36..40].try_into().unwrap())
+    }
+
+    #[tokio::test]
+    async fn replies_matched_by_sequence() {
+        let mut dispatcher = Dispatcher::new();
+        let manager = AudioKeyManager::new(dispatcher.sender());
+        let (first, first_seq) = start_request(&mut dispatcher, &manager, FileId([1; 20])).await;
+        let (second, second_seq) = start_request(&mut dispatcher, &manager, FileId([2; 20])).await;
+
+        // answered out of order
+        let reply = [&second_seq[..], &[2; AUDIO_KEY_SIZE]].concat();
+        manager.handle(PacketType::AesKey, reply.into()).unwrap();
+        let reply = [&first_seq[..], &[1; AUDIO_KEY_SIZE]].concat();
+        manager.handle(PacketType::AesKey, reply.into()).unwrap();
+
+        assert_eq!(first.await.unwrap().unwrap(), AudioKey([1; AUDIO_KEY_SIZE]));
+        assert_eq!(second.await.unwrap().unwrap(), AudioKey([2; AUDIO_KEY_SIZE]));
+    }
+
+    #[tokio::test]
+    async fn key_error_is_remote() {
+        let mut dispatcher = Dispatcher::new();
+        let manager = AudioKeyManager::new(dispatcher.sender());
+        let (request, seq) = start_request(&mut dispatcher, &manager, FileId([1; 20])).await;
+
+        let reply = [&seq[..], &[0x00, 0x01]].concat();
+        manager.handle(PacketType::AesKeyError, reply.into()).unwrap();
+
+        let result = request.await.unwrap();
+        assert!(matches!(result, Err(Error::AudioKey(AudioKeyError::Remote(1)))));
+    }
+
+    #[tokio::test]
+    async fn unanswered_request_times_out() {
+        let dispatcher = Dispatcher::new();
+        let manager = AudioKeyManager::new(dispatcher.sender());
+
+        let timeout = Duration::from_millis(10);
+        let result = manager.request_with_timeout(&track(), FileId([1; 20]), timeout).await;
+        assert!(matches!(result, Err(Error::AudioKey(AudioKeyError::Timeout))));
+        assert!(manager.0.state.lock().unwrap().pending.is_empty());
+    }
+
+    #[tokio::test]
+    async fn close_fails_pending_requests() {
+        let mut dispatcher = Dispatcher::new();
+        let manager = AudioKeyManager::new(dispatcher.sender());
+        let (request, _) = start_request(&mut dispatcher, &manager, FileId([1; 20])).await;
+
+        manager.close();
+        assert!(matches!(request.await.unwrap(), Err(Error::SessionClosed)));
+    }
+}
@@ -0,0 +1,15 @@
+use fyspoti::Result;
+use fyspoti::mock::MockAccessPoint;
+use tokio::net::TcpListener;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let addr = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "127.0.0.1:4070".to_owned());
+
+    let listener = TcpListener::bind(&addr).await?;
+    println!("mock access point listening on {}", listener.local_addr()?);
+
+    MockAccessPoint::new().listen(listener).await
+}
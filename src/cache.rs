@@ -0,0 +1,132 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use protobuf::Enum;
+use serde::{Deserialize, Serialize};
+
+use crate::client::Credentials;
+use crate::error::{Error, Result};
+use crate::protocol::authentication::AuthenticationType;
+
+#[derive(Serialize, Deserialize)]
+struct StoredCredentials {
+    username: String,
+    auth_type: i32,
+    auth_data: String,
+}
+
+pub struct CredentialsCache {
+    dir: PathBuf,
+}
+
+impl CredentialsCache {
+    pub fn new(dir: impl Into<PathBuf>) -> CredentialsCache {
+        CredentialsCache { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn load(&self, username: &str) -> Result<Option<Credentials>> {
+        let data = match fs::read(self.path(username)) {
+            Ok(data) => data,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(Error::Cache(e)),
+        };
+
+        let stored: StoredCredentials = serde_json::from_slice(&data).map_err(invalid_data)?;
+        let auth_type = AuthenticationType::from_i32(stored.auth_type)
+            .ok_or_else(|| invalid_data(format!("unknown auth type {}", stored.auth_type)))?;
+        let auth_data = STANDARD.decode(stored.auth_data).map_err(invalid_data)?;
+
+        Ok(Some(Credentials {
+            username: Some(stored.username),
+            auth_type,
+            auth_data,
+        }))
+    }
+
+    pub fn save(&self, credentials: &Credentials) -> Result<()> {
+        let username = credentials
+            .username
+            .as_deref()
+            .ok_or_else(|| invalid_data("credentials without username"))?;
+        self.save_as(username, credentials)
+    }
+
+    // stores under the name used to log in, which may be an email address
+    // rather than the canonical username the credentials carry
+    pub fn save_as(&self, login: &str, credentials: &Credentials) -> Result<()> {
+        let username = credentials
+            .username
+            .as_deref()
+            .ok_or_else(|| invalid_data("credentials without username"))?;
+
+        let stored = StoredCredentials {
+            username: username.to_owned(),
+            auth_type: credentials.auth_type.value(),
+            auth_data: STANDARD.encode(&credentials.auth_data),
+        };
+        let data = serde_json::to_vec(&stored).map_err(invalid_data)?;
+
+        create_private_dir(&self.dir).map_err(Error::Cache)?;
+
+        // write next to the target and rename, so a crash never leaves a truncated file
+        let path = self.path(login);
+        let tmp = path.with_extension("tmp");
+        let _ = fs::remove_file(&tmp);
+        let mut file = create_private_file(&tmp).map_err(Error::Cache)?;
+        file.write_all(&data).map_err(Error::Cache)?;
+        file.sync_all().map_err(Error::Cache)?;
+        fs::rename(&tmp, &path).map_err(Error::Cache)
+    }
+
+    pub fn remove(&self, username: &str) -> Result<()> {
+        match fs::remove_file(self.path(username)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(Error::Cache(e)),
+            _ => Ok(()),
+        }
+    }
+
+    fn path(&self, username: &str) -> PathBuf {
+        let name = URL_SAFE_NO_PAD.encode(username.as_bytes());
+        self.dir.join(format!("{name}.json"))
+    }
+}
+
+fn invalid_data<E: Into<Box<dyn std::error::Error + Send + Sync>>>(e: E) -> Error {
+    Error::Cache(io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(unix)]
+fn create_private_dir(dir: &Path) -> io::Result<()> {
+    use std::os::unix::fs::DirBuilderExt;
+
+    fs::DirBuilder::new().recursive(true).mode(0o700).create(dir)
+}
+
+#[cfg(not(unix))]
+fn create_private_dir(dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)
+}
+
+#[cfg(unix)]
+fn create_private_file(path: &Path) -> io::Result<fs::File> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)
+}
+
+#[cfg(not(unix))]
+fn create_private_file(path: &Path) -> io::Result<fs::File> {
+    fs::File::create(path)
+}
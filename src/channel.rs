@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use bytes::{Buf, BufMut, Bytes};
+use futures_util::Stream;
+use log::{debug, trace};
+use thiserror::Error;
+use tokio::sync::mpsc;
+
+use crate::consts::PacketType;
+use crate::dispatch::{PacketHandler, PacketSender};
+use crate::error::{Error, Result};
+use crate::spotify_id::FileId;
+
+// StreamChunk request
+// +------------+--------------------------------+---------+-----------+---------+
+// | CHANNEL_ID | FIXED PARAMETERS               | FILE_ID | START     | END     |
+// +------------+--------------------------------+---------+-----------+---------+
+// | u16        | 0x00 0x01 0x0000 0x00000000    | 20      | u32       | u32     |
+// |            | 0x00009c40 0x00020000          | bytes   | (words)   | (words) |
+// +------------+--------------------------------+---------+-----------+---------+
+// StreamChunkRes: CHANNEL_ID u16, then while in the header section a list of
+// [len u16, id u8, len - 1 bytes] ended by len == 0, then raw data chunks. An
+// empty data chunk ends the channel.
+// ChannelError: CHANNEL_ID u16, CODE u16.
+
+#[derive(Debug, Error)]
+pub enum ChannelError {
+    #[error("channel failed with code {0}")]
+    Remote(u16),
+    #[error("channel aborted by the access point")]
+    Aborted,
+    #[error("malformed channel packet")]
+    Malformed,
+    #[error("no free channel id")]
+    Exhausted,
+}
+
+#[derive(Default)]
+struct ChannelState {
+    next_id: u16,
+    channels: HashMap<u16, mpsc::UnboundedSender<(PacketType, Bytes)>>,
+}
+
+struct ChannelInner {
+    sender: PacketSender,
+    state: Mutex<ChannelState>,
+}
+
+#[derive(Clone)]
+pub struct ChannelManager(Arc<ChannelInner>);
+
+impl ChannelManager {
+    pub fn new(sender: PacketSender) -> ChannelManager {
+        ChannelManager(Arc::new(ChannelInner {
+            sender,
+            state: Mutex::new(ChannelState::default()),
+        }))
+    }
+
+    fn allocate(&self) -> Result<(u16, mpsc::UnboundedReceiver<(PacketType, Bytes)>)> {
+        let mut state = self.0.state.lock().unwrap();
+        if state.channels.len() > u16::MAX as usize {
+            return Err(ChannelError::Exhausted.into());
+        }
+
+        let mut id = state.next_id;
+        while state.channels.contains_key(&id) {
+            id = id.wrapping_add(1);
+        }
+        state.next_id = id.wrapping_add(1);
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        state.channels.insert(id, tx);
+        Ok((id, rx))
+    }
+
+    // The id may already have been freed by a ChannelError and handed to a
+    // new channel, only an entry whose receiver is gone belongs to the caller.
+    fn release(&self, id: u16) {
+        let mut state = self.0.state.lock().unwrap();
+        if state.channels.get(&id).is_some_and(|tx| tx.is_closed()) {
+            state.channels.remove(&id);
+        }
+    }
+
+    // Requests bytes `start..end` of a file. Offsets are sent as 4 byte
+    // words, `start` is rounded down and `end` up.
+    pub fn request(&self, file: FileId, start: u32, end: u32) -> Result<Channel> {
+        let (id, rx) = self.allocate()?;
+        let channel = Channel {
+            id,
+            rx,
+            state: ChannelPhase::Header,
+            headers: Vec::new(),
+            manager: self.clone(),
+        };
+
+        let mut data = Vec::with_capacity(2 + 1 + 1 + 2 + 4 + 4 + 4 + 20 + 4 + 4);
+        data.put_u16(id);
+        data.put_u8(0);
+        data.put_u8(1);
+        data.put_u16(0);
+        data.put_u32(0);
+        data.put_u32(0x0000_9c40);
+        data.put_u32(0x0002_0000);
+        data.put_slice(&file.0);
+        data.put_u32(start / 4);
+        data.put_u32(end.div_ceil(4));
+
+        debug!("requesting {file} bytes {start}..{end} on channel {id}");
+        self.0.sender.send(PacketType::StreamChunk, data)?;
+        Ok(channel)
+    }
+
+    fn dispatch(&self, cmd: PacketType, mut data: Bytes) -> Result<()> {
+        let id = read_u16(&mut data)?;
+
+        let tx = {
+            let mut state = self.0.state.lock().unwrap();
+            match cmd {
+                // the channel is done either way
+                PacketType::ChannelError | PacketType::ChannelAbort => state.channels.remove(&id),
+                _ => state.channels.get(&id).cloned(),
+            }
+        };
+        match tx {
+            Some(tx) => {
+                let _ = tx.send((cmd, data));
+            }
+            None => trace!("ignoring {cmd:?} for unknown channel {id}"),
+        }
+        Ok(())
+    }
+}
+
+impl PacketHandler for ChannelManager {
+    fn handle(&self, cmd: PacketType, data: Bytes) -> Result<()> {
+        self.dispatch(cmd, data)
+    }
+
+    // every open channel sees its sender go away and fails with SessionClosed
+    fn close(&self) {
+        self.0.state.lock().unwrap().channels.clear();
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ChannelPhase {
+    Header,
+    Data,
+    Closed,
+}
+
+// Data of one StreamChunk request. Headers are collected as they arrive and
+// are complete once the first data chunk has been yielded.
+pub struct Channel {
+    id: u16,
+    rx: mpsc::UnboundedReceiver<(PacketType, Bytes)>,
+    state: ChannelPhase,
+    headers: Vec<(u8, Bytes)>,
+    manager: ChannelManager,
+}
+
+impl Channel {
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    pub fn headers(&self) -> &[(u8, Bytes)] {
+        &self.headers
+    }
+
+    pub fn header(&self, id: u8) -> Option<&Bytes> {
+        self.headers.iter().find(|(h, _)| *h == id).map(|(_, data)| data)
+    }
+
+    fn read_headers(&mut self, mut data: Bytes) -> Result<()> {
+        while !data.is_empty() {
+            let len = read_u16(&mut data)? as usize;
+            if len == 0 {
+                self.state = ChannelPhase::Data;
+                break;
+            }
+            if data.len() < len {
+                return Err(ChannelError::Malformed.into());
+            }
+            let mut header = data.split_to(len);
+            let id = header.get_u8();
+            self.headers.push((id, header));
+        }
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        self.state = ChannelPhase::Closed;
+        self.rx.close();
+        self.manager.release(self.id);
+    }
+}
+
+impl Stream for Channel {
+    type Item = Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.state == ChannelPhase::Closed {
+                return Poll::Ready(None);
+            }
+
+            let (cmd, mut data) = match self.rx.poll_recv(cx) {
+                Poll::Ready(Some(packet)) => packet,
+                // the session went away
+                Poll::Ready(None) => {
+                    self.close();
+                    return Poll::Ready(Some(Err(Error::SessionClosed)));
+                }
+                Poll::Pending => return Poll::Pending,
+            };
+
+            match cmd {
+                PacketType::ChannelError => {
+                    self.close();
+                    let error = match read_u16(&mut data) {
+                        Ok(code) => ChannelError::Remote(code).into(),
+                        Err(e) => e,
+                    };
+                    return Poll::Ready(Some(Err(error)));
+                }
+                PacketType::ChannelAbort => {
+                    self.close();
+                    return Poll::Ready(Some(Err(ChannelError::Aborted.into())));
+                }
+                _ if self.state == ChannelPhase::Header => {
+                    if let Err(e) = self.read_headers(data) {
+                        self.close();
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+                _ if data.is_empty() => {
+                    self.close();
+                    return Poll::Ready(None);
+                }
+                _ => return Poll::Ready(Some(Ok(data))),
+            }
+        }
+    }
+}
+
+impl Drop for Channel {
+    fn drop(&mut self) {
+        if self.state != ChannelPhase::Closed {
+            self.rx.close();
+            self.manager.release(self.id);
+        }
+    }
+}
+
+fn read_u16(data: &mut Bytes) -> Result<u16> {
+    if data.len() < 2 {
+        return Err(ChannelError::Malformed.into());
+    }
+    Ok(data.get_u16())
+}
@@ -0,0 +1,450 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures_util::{Sink, SinkExt, Stream, StreamExt};
+use log::{debug, warn};
+use protobuf::Message;
+use tokio::io::{self, AsyncRead, AsyncWrite};
+use tokio::net::TcpStream;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+use crate::apresolve::{ApResolveError, ApResolver};
+use crate::audio_key::AudioKeyManager;
+use crate::channel::ChannelManager;
+use crate::consts::PacketType;
+use crate::dispatch::{CloseOnDrop, Dispatcher, HandlerRegistry, PacketHandler, PacketSender};
+use crate::error::{Error, Phase, Result, with_timeout};
+use crate::handshake::{self, HandshakeConfig};
+use crate::keepalive::{DEFAULT_PING_TIMEOUT, KeepAlive};
+use crate::mercury::MercuryManager;
+use crate::packet::Packet;
+use crate::proxy::{Proxy, ProxyError};
+use crate::recorder::{Recorded, Recorder};
+use crate::session_info::{SessionInfo, SessionInfoHandler};
+
+use crate::protocol::authentication::{
+    APWelcome, AccountType, AuthenticationType, ClientResponseEncrypted,
+};
+use crate::protocol::keyexchange::{APLoginFailed, ErrorCode};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Credentials {
+    pub username: Option<String>,
+    pub auth_type: AuthenticationType,
+    pub auth_data: Vec<u8>,
+}
+
+impl Credentials {
+    pub fn with_password(username: impl Into<String>, password: impl Into<String>) -> Credentials {
+        Credentials {
+            username: Some(username.into()),
+            auth_type: AuthenticationType::AUTHENTICATION_USER_PASS,
+            auth_data: password.into().into_bytes(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Welcome {
+    pub canonical_username: String,
+    pub reusable_credentials: Credentials,
+    pub account_type: AccountType,
+}
+
+impl From<APWelcome> for Welcome {
+    fn from(welcome: APWelcome) -> Self {
+        let canonical_username = welcome.canonical_username().to_owned();
+        let reusable_credentials = Credentials {
+            username: Some(canonical_username.clone()),
+            auth_type: welcome.reusable_auth_credentials_type(),
+            auth_data: welcome.reusable_auth_credentials().to_owned(),
+        };
+
+        Welcome {
+            canonical_username,
+            reusable_credentials,
+            account_type: welcome.account_type_logged_in(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LoginFailure {
+    pub error_code: ErrorCode,
+    pub retry_delay: i32,
+    pub error_description: String,
+}
+
+impl From<APLoginFailed> for LoginFailure {
+    fn from(failed: APLoginFailed) -> Self {
+        LoginFailure {
+            error_code: failed.error_code(),
+            retry_delay: failed.retry_delay(),
+            error_description: failed.error_description().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoginFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.error_code)?;
+        if !self.error_description.is_empty() {
+            write!(f, ": {}", self.error_description)?;
+        }
+        if self.retry_delay > 0 {
+            write!(f, " (retry in {}s)", self.retry_delay)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SessionConfig {
+    pub resolver: ApResolver,
+    pub handshake: HandshakeConfig,
+    pub ping_timeout: Duration,
+    pub connect_timeout: Duration,
+    pub login_timeout: Duration,
+    // used for apresolve and tunnels the AP connection
+    pub proxy: Option<Proxy>,
+    // record every decrypted frame to this file, see `recorder`
+    pub record: Option<PathBuf>,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        SessionConfig {
+            resolver: ApResolver::default(),
+            handshake: HandshakeConfig::default(),
+            ping_timeout: DEFAULT_PING_TIMEOUT,
+            connect_timeout: Duration::from_secs(10),
+            login_timeout: Duration::from_secs(20),
+            proxy: None,
+            record: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Session(Arc<SessionInternal>);
+
+struct SessionInternal {
+    welcome: Welcome,
+    sender: PacketSender,
+    handlers: HandlerRegistry,
+    mercury: MercuryManager,
+    channels: ChannelManager,
+    audio_keys: AudioKeyManager,
+    info: watch::Receiver<SessionInfo>,
+    closed: watch::Receiver<bool>,
+    error: Arc<Mutex<Option<Error>>>,
+    task: JoinHandle<()>,
+}
+
+impl Session {
+    pub async fn connect(credentials: Credentials) -> Result<Session> {
+        Session::connect_with_config(SessionConfig::default(), credentials).await
+    }
+
+    pub async fn connect_with_config(config: SessionConfig, credentials: Credentials) -> Result<Session> {
+        // one recording for the whole call, every AP attempt appends to it
+        if let Some(path) = &config.record {
+            Recorder::create(path)?;
+        }
+
+        let mut last_error = None;
+        for ap in config.resolver.resolve(config.proxy.as_ref()).await {
+            let connect = async {
+                match &config.proxy {
+                    Some(proxy) => proxy.connect(&ap.host, ap.port).await,
+                    None => Ok(TcpStream::connect((ap.host.as_str(), ap.port)).await?),
+                }
+            };
+            let result = match with_timeout(Phase::Connect, config.connect_timeout, connect).await {
+                Ok(stream) => Session::connect_attempt(stream, config.clone(), credentials.clone(), true).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Err(e) if try_next_ap(&e) => {
+                    warn!("failed to connect to {ap}: {e}");
+                    last_error = Some(e);
+                }
+                result => return result,
+            }
+        }
+
+        Err(last_error.unwrap_or(ApResolveError::Empty.into()))
+    }
+
+    pub async fn connect_with<T>(conn: T, config: SessionConfig, credentials: Credentials) -> Result<Session>
+    where
+        T: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
+        Session::connect_attempt(conn, config, credentials, false).await
+    }
+
+    // `append_recording` keeps what earlier attempts of the same connect recorded
+    async fn connect_attempt<T>(
+        conn: T,
+        config: SessionConfig,
+        credentials: Credentials,
+        append_recording: bool,
+    ) -> Result<Session>
+    where
+        T: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
+        let transport = handshake::handshake_with_config(conn, &config.handshake).await?;
+        let recorder = match config.record.as_deref() {
+            Some(path) if append_recording => Some(Recorder::append(path)?),
+            Some(path) => Some(Recorder::create(path)?),
+            None => None,
+        };
+        let mut transport = Recorded::new(transport, recorder);
+
+        let device_id = uuid::Uuid::new_v4().as_hyphenated().to_string();
+        let welcome = with_timeout(
+            Phase::Login,
+            config.login_timeout,
+            authenticate(&mut transport, credentials, &device_id, &config.handshake),
+        )
+        .await?;
+
+        let dispatcher = Dispatcher::new();
+        let sender = dispatcher.sender();
+        let handlers = dispatcher.handlers();
+
+        let keepalive = Arc::new(KeepAlive::new(sender.clone()));
+        handlers.register(&[PacketType::Ping, PacketType::PongAck], keepalive.clone());
+
+        let mercury = MercuryManager::new(sender.clone());
+        handlers.register(
+            &[
+                PacketType::MercuryReq,
+                PacketType::MercurySub,
+                PacketType::MercuryUnsub,
+                PacketType::MercuryEvent,
+            ],
+            mercury.clone(),
+        );
+
+        let channels = ChannelManager::new(sender.clone());
+        handlers.register(
+            &[
+                PacketType::StreamChunkRes,
+                PacketType::ChannelError,
+                PacketType::ChannelAbort,
+            ],
+            channels.clone(),
+        );
+
+        let audio_keys = AudioKeyManager::new(sender.clone());
+        handlers.register(&[PacketType::AesKey, PacketType::AesKeyError], audio_keys.clone());
+
+        let (info_handler, info) = SessionInfoHandler::new();
+        handlers.register(
+            &[
+                PacketType::CountryCode,
+                PacketType::ProductInfo,
+                PacketType::LicenseVersion,
+                PacketType::PreferredLocale,
+            ],
+            info_handler,
+        );
+
+        let (closed_tx, closed) = watch::channel(false);
+        let error = Arc::new(Mutex::new(None));
+        let close = CloseOnDrop(handlers.clone());
+        let task = tokio::spawn({
+            let error = error.clone();
+            async move {
+                let _close = close;
+                let result = tokio::select! {
+                    result = dispatcher.run(transport) => result,
+                    e = keepalive.watch(config.ping_timeout) => Err(e),
+                };
+                if let Err(e) = result {
+                    debug!("session closed: {e}");
+                    *error.lock().unwrap() = Some(e);
+                }
+                let _ = closed_tx.send(true);
+            }
+        });
+
+        Ok(Session(Arc::new(SessionInternal {
+            welcome,
+            sender,
+            handlers,
+            mercury,
+            channels,
+            audio_keys,
+            info,
+            closed,
+            error,
+            task,
+        })))
+    }
+
+    pub fn welcome(&self) -> &Welcome {
+        &self.0.welcome
+    }
+
+    pub fn username(&self) -> &str {
+        &self.0.welcome.canonical_username
+    }
+
+    pub fn mercury(&self) -> &MercuryManager {
+        &self.0.mercury
+    }
+
+    pub fn channels(&self) -> &ChannelManager {
+        &self.0.channels
+    }
+
+    pub fn audio_keys(&self) -> &AudioKeyManager {
+        &self.0.audio_keys
+    }
+
+    pub fn info(&self) -> SessionInfo {
+        self.0.info.borrow().clone()
+    }
+
+    // yields the current info first, then every change
+    pub fn watch_info(&self) -> watch::Receiver<SessionInfo> {
+        let mut info = self.0.info.clone();
+        info.mark_changed();
+        info
+    }
+
+    // ProductInfo follows the welcome closely; resolves once it is in, even
+    // if it carried no attributes
+    pub async fn wait_for_attributes(&self) -> Result<SessionInfo> {
+        let mut info = self.0.info.clone();
+        let mut closed = self.0.closed.clone();
+        tokio::select! {
+            result = info.wait_for(|info| info.attributes.is_some()) => {
+                result.map(|info| info.clone()).map_err(|_| Error::SessionClosed)
+            }
+            _ = closed.wait_for(|closed| *closed) => Err(Error::SessionClosed),
+        }
+    }
+
+    pub fn sender(&self) -> PacketSender {
+        self.0.sender.clone()
+    }
+
+    pub fn send_packet(&self, cmd: PacketType, data: impl Into<Bytes>) -> Result<()> {
+        self.0.sender.send(cmd, data)
+    }
+
+    pub fn register_handler<H: PacketHandler>(&self, kinds: &[PacketType], handler: H) {
+        self.0.handlers.register(kinds, handler);
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.0.task.is_finished()
+    }
+
+    pub async fn wait(&self) -> Result<()> {
+        let mut closed = self.0.closed.clone();
+        let _ = closed.wait_for(|closed| *closed).await;
+
+        match &*self.0.error.lock().unwrap() {
+            Some(e) => Err(terminal_error(e)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn shutdown(&self) {
+        self.0.task.abort();
+    }
+}
+
+impl Drop for SessionInternal {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+// every clone of a session reports the same end, but io::Error is not Clone
+fn terminal_error(e: &Error) -> Error {
+    match e {
+        Error::Transport(e) => Error::Transport(io::Error::new(e.kind(), e.to_string())),
+        Error::Codec(e) => Error::Codec(e.clone()),
+        Error::ConnectionLost => Error::ConnectionLost,
+        _ => Error::SessionClosed,
+    }
+}
+
+// errors that are specific to one access point, the next one may well work
+fn try_next_ap(e: &Error) -> bool {
+    match e {
+        // a stalled or unreachable AP says nothing about the others
+        Error::Transport(_) | Error::Timeout(_) => true,
+        // proxies commonly allow some ports only, e.g. 443 but not 4070; bad
+        // credentials or a broken proxy fail the same way for every AP
+        Error::Proxy(ProxyError::ConnectRejected(_) | ProxyError::SocksReply(_)) => true,
+        Error::LoginFailed(failure) => failure.error_code == ErrorCode::TryAnotherAP,
+        _ => false,
+    }
+}
+
+pub async fn authenticate<S>(
+    transport: &mut S,
+    credentials: Credentials,
+    device_id: &str,
+    config: &HandshakeConfig,
+) -> Result<Welcome>
+where
+    S: Stream<Item = Result<Packet>> + Sink<Packet, Error = Error> + Unpin,
+{
+    let mut packet = ClientResponseEncrypted::new();
+    if let Some(username) = credentials.username {
+        packet
+            .login_credentials
+            .mut_or_insert_default()
+            .set_username(username);
+    }
+    packet
+        .login_credentials
+        .mut_or_insert_default()
+        .set_typ(credentials.auth_type);
+    packet
+        .login_credentials
+        .mut_or_insert_default()
+        .set_auth_data(credentials.auth_data);
+    let (cpu_family, os) = config.system_info();
+    packet
+        .system_info
+        .mut_or_insert_default()
+        .set_cpu_family(cpu_family);
+    packet.system_info.mut_or_insert_default().set_os(os);
+    packet
+        .system_info
+        .mut_or_insert_default()
+        .set_device_id(device_id.to_owned());
+
+    let cmd = PacketType::Login;
+    let data = packet.write_to_bytes()?;
+
+    transport.send(Packet::new(cmd, data)).await?;
+
+    let packet = transport.next().await.ok_or_else(|| {
+        io::Error::new(io::ErrorKind::UnexpectedEof, "connection closed during login")
+    })??;
+
+    match packet.kind() {
+        PacketType::APWelcome => {
+            let welcome = APWelcome::parse_from_bytes(&packet.payload)?;
+            Ok(welcome.into())
+        }
+        PacketType::AuthFailure => {
+            let failed = APLoginFailed::parse_from_bytes(&packet.payload)?;
+            Err(Error::LoginFailed(failed.into()))
+        }
+        _ => Err(Error::UnexpectedPacket(packet.cmd())),
+    }
+}
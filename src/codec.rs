@@ -1,9 +1,18 @@
 use std::io;
 
-use bytes::{BufMut, Bytes, BytesMut};
+use bytes::{BufMut, BytesMut};
+use hmac::{Hmac, Mac};
+use rc4::{KeyInit, Rc4, StreamCipher, consts::U16};
+use sha1::Sha1;
 use shannon::Shannon;
+use thiserror::Error;
 use tokio_util::codec::{Decoder, Encoder};
 
+use crate::consts::PacketType;
+use crate::error::{Error, Result};
+use crate::packet::Packet;
+use crate::protocol::keyexchange::Cryptosuite;
+
 // +---------+------------------+----------------+
 // | HEADER  |     PAYLOAD      |      MAC       |
 // +---------+------------------+----------------+
@@ -18,53 +27,199 @@ use tokio_util::codec::{Decoder, Encoder};
 // - MAC covers encrypted HEADER + PAYLOAD
 // - N = PAYLOAD_SIZE
 // - FRAME_SIZE = 3 + N + 4
-// - Nonce increments by 1 per frame
+// - Nonce increments by 1 per frame, a connection ends when it runs out
 
 const HEADER_SIZE: usize = 3;
 const MAC_SIZE: usize = 4;
+pub const MAX_PAYLOAD_SIZE: usize = u16::MAX as usize;
+
+#[derive(Debug, Clone, Error)]
+pub enum CodecError {
+    #[error("MAC mismatch")]
+    MacMismatch,
+    #[error("payload of {0} bytes does not fit in a frame")]
+    FrameTooLarge(usize),
+    #[error("frame nonce exhausted")]
+    NonceExhausted,
+    #[error("decoder unusable after an earlier failure")]
+    Poisoned,
+    #[error("cipher key of {0} bytes is too short")]
+    KeyTooShort(usize),
+}
+
+// Per-frame cipher and MAC. `set_nonce` starts a frame, `finish` and
+// `check_mac` end it.
+pub trait FrameCipher: Send {
+    fn set_nonce(&mut self, nonce: u32);
+    fn encrypt(&mut self, buf: &mut [u8]);
+    fn decrypt(&mut self, buf: &mut [u8]);
+    fn finish(&mut self, mac: &mut [u8]);
+    fn check_mac(&mut self, mac: &[u8]) -> bool;
+}
+
+impl FrameCipher for Shannon {
+    fn set_nonce(&mut self, nonce: u32) {
+        self.nonce_u32(nonce);
+    }
+
+    fn encrypt(&mut self, buf: &mut [u8]) {
+        Shannon::encrypt(self, buf);
+    }
+
+    fn decrypt(&mut self, buf: &mut [u8]) {
+        Shannon::decrypt(self, buf);
+    }
+
+    fn finish(&mut self, mac: &mut [u8]) {
+        Shannon::finish(self, mac);
+    }
+
+    fn check_mac(&mut self, mac: &[u8]) -> bool {
+        Shannon::check_mac(self, mac).is_ok()
+    }
+}
+
+impl FrameCipher for Box<dyn FrameCipher> {
+    fn set_nonce(&mut self, nonce: u32) {
+        (**self).set_nonce(nonce);
+    }
+
+    fn encrypt(&mut self, buf: &mut [u8]) {
+        (**self).encrypt(buf);
+    }
+
+    fn decrypt(&mut self, buf: &mut [u8]) {
+        (**self).decrypt(buf);
+    }
+
+    fn finish(&mut self, mac: &mut [u8]) {
+        (**self).finish(mac);
+    }
+
+    fn check_mac(&mut self, mac: &[u8]) -> bool {
+        (**self).check_mac(mac)
+    }
+}
+
+type HmacSha1 = Hmac<Sha1>;
+
+const RC4_KEY_SIZE: usize = 16;
+
+// RC4 keyed with the first 16 bytes of the session key, the keystream runs
+// across frames. The MAC is HMAC-SHA1 keyed with the remaining bytes over
+// nonce || ciphertext, truncated to MAC_SIZE.
+pub struct Rc4HmacCipher {
+    cipher: Rc4<U16>,
+    mac_init: HmacSha1,
+    mac: HmacSha1,
+}
+
+impl Rc4HmacCipher {
+    pub fn new(key: &[u8]) -> Result<Rc4HmacCipher> {
+        if key.len() < RC4_KEY_SIZE {
+            return Err(CodecError::KeyTooShort(key.len()).into());
+        }
+        let (cipher_key, mac_key) = key.split_at(RC4_KEY_SIZE);
+        let mac = <HmacSha1 as Mac>::new_from_slice(mac_key).expect("hmac accepts any key length");
+        Ok(Rc4HmacCipher {
+            cipher: Rc4::new(cipher_key.into()),
+            mac_init: mac.clone(),
+            mac,
+        })
+    }
+}
+
+impl FrameCipher for Rc4HmacCipher {
+    fn set_nonce(&mut self, nonce: u32) {
+        self.mac = self.mac_init.clone();
+        self.mac.update(&nonce.to_be_bytes());
+    }
+
+    fn encrypt(&mut self, buf: &mut [u8]) {
+        self.cipher.apply_keystream(buf);
+        self.mac.update(buf);
+    }
+
+    fn decrypt(&mut self, buf: &mut [u8]) {
+        self.mac.update(buf);
+        self.cipher.apply_keystream(buf);
+    }
+
+    fn finish(&mut self, mac: &mut [u8]) {
+        let digest = self.mac.clone().finalize().into_bytes();
+        mac.copy_from_slice(&digest[..mac.len()]);
+    }
+
+    fn check_mac(&mut self, mac: &[u8]) -> bool {
+        self.mac.clone().verify_truncated_left(mac).is_ok()
+    }
+}
 
 #[derive(Debug)]
 enum DecodeState {
     Header,
     Payload(u8, usize),
+    // the cipher state can't be trusted after a MAC failure
+    Poisoned,
 }
 
-pub struct ApCodec {
-    encode_nonce: u32,
-    encode_cipher: Shannon,
+pub struct ApCodec<C = Box<dyn FrameCipher>> {
+    // None once every nonce has been used
+    encode_nonce: Option<u32>,
+    encode_cipher: C,
 
-    decode_nonce: u32,
-    decode_cipher: Shannon,
+    decode_nonce: Option<u32>,
+    decode_cipher: C,
     decode_state: DecodeState,
 }
 
 impl ApCodec {
+    // Shannon, the suite every AP speaks
     pub fn new(send_key: &[u8], recv_key: &[u8]) -> ApCodec {
+        ApCodec::with_ciphers(Box::new(Shannon::new(send_key)), Box::new(Shannon::new(recv_key)))
+    }
+
+    pub fn with_cryptosuite(suite: Cryptosuite, send_key: &[u8], recv_key: &[u8]) -> Result<ApCodec> {
+        let (encode_cipher, decode_cipher): (Box<dyn FrameCipher>, Box<dyn FrameCipher>) = match suite {
+            Cryptosuite::CRYPTO_SUITE_SHANNON => {
+                (Box::new(Shannon::new(send_key)), Box::new(Shannon::new(recv_key)))
+            }
+            Cryptosuite::CRYPTO_SUITE_RC4_SHA1_HMAC => (
+                Box::new(Rc4HmacCipher::new(send_key)?),
+                Box::new(Rc4HmacCipher::new(recv_key)?),
+            ),
+        };
+        Ok(ApCodec::with_ciphers(encode_cipher, decode_cipher))
+    }
+}
+
+impl<C: FrameCipher> ApCodec<C> {
+    pub fn with_ciphers(encode_cipher: C, decode_cipher: C) -> ApCodec<C> {
         ApCodec {
-            encode_nonce: 0,
-            encode_cipher: Shannon::new(send_key),
+            encode_nonce: Some(0),
+            encode_cipher,
 
-            decode_nonce: 0,
-            decode_cipher: Shannon::new(recv_key),
+            decode_nonce: Some(0),
+            decode_cipher,
             decode_state: DecodeState::Header,
         }
     }
-}
 
-impl Encoder<(u8, Vec<u8>)> for ApCodec {
-    type Error = io::Error;
+    fn encode_frame(&mut self, cmd: u8, payload: &[u8], buf: &mut BytesMut) -> Result<()> {
+        if payload.len() > MAX_PAYLOAD_SIZE {
+            return Err(CodecError::FrameTooLarge(payload.len()).into());
+        }
+        let nonce = self.encode_nonce.ok_or(CodecError::NonceExhausted)?;
+        self.encode_nonce = nonce.checked_add(1);
 
-    fn encode(&mut self, item: (u8, Vec<u8>), buf: &mut BytesMut) -> io::Result<()> {
-        let (cmd, payload) = item;
         let offset = buf.len();
 
         buf.reserve(3 + payload.len());
         buf.put_u8(cmd);
         buf.put_u16(payload.len() as u16);
-        buf.extend_from_slice(&payload);
+        buf.extend_from_slice(payload);
 
-        self.encode_cipher.nonce_u32(self.encode_nonce);
-        self.encode_nonce += 1;
+        self.encode_cipher.set_nonce(nonce);
 
         self.encode_cipher.encrypt(&mut buf[offset..]);
 
@@ -76,42 +231,70 @@ impl Encoder<(u8, Vec<u8>)> for ApCodec {
     }
 }
 
-impl Decoder for ApCodec {
-    type Item = (u8, Bytes);
-    type Error = io::Error;
+impl<C: FrameCipher> Encoder<Packet> for ApCodec<C> {
+    type Error = Error;
+
+    fn encode(&mut self, packet: Packet, buf: &mut BytesMut) -> Result<()> {
+        self.encode_frame(packet.cmd(), &packet.payload, buf)
+    }
+}
 
-    fn decode(&mut self, buf: &mut BytesMut) -> io::Result<Option<(u8, Bytes)>> {
-        if let DecodeState::Header = self.decode_state {
-            if buf.len() >= HEADER_SIZE {
-                let mut header = [0u8; HEADER_SIZE];
-                header.copy_from_slice(buf.split_to(HEADER_SIZE).as_ref());
+// borrowed payloads go straight into the frame buffer
+impl<C: FrameCipher> Encoder<(PacketType, &[u8])> for ApCodec<C> {
+    type Error = Error;
 
-                self.decode_cipher.nonce_u32(self.decode_nonce);
-                self.decode_nonce += 1;
+    fn encode(&mut self, (kind, payload): (PacketType, &[u8]), buf: &mut BytesMut) -> Result<()> {
+        self.encode_frame(kind as u8, payload, buf)
+    }
+}
 
-                self.decode_cipher.decrypt(&mut header);
+impl<C: FrameCipher> Decoder for ApCodec<C> {
+    type Item = Packet;
+    type Error = Error;
 
-                let cmd = header[0];
-                let size = u16::from_be_bytes([header[1], header[2]]) as usize;
-                self.decode_state = DecodeState::Payload(cmd, size);
-            }
+    fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<Packet>> {
+        if let DecodeState::Poisoned = self.decode_state {
+            return Err(CodecError::Poisoned.into());
         }
 
-        if let DecodeState::Payload(cmd, size) = self.decode_state {
-            if buf.len() >= size + MAC_SIZE {
-                self.decode_state = DecodeState::Header;
+        if let DecodeState::Header = self.decode_state
+            && buf.len() >= HEADER_SIZE
+        {
+            let mut header = [0u8; HEADER_SIZE];
+            header.copy_from_slice(buf.split_to(HEADER_SIZE).as_ref());
+
+            let Some(nonce) = self.decode_nonce else {
+                self.decode_state = DecodeState::Poisoned;
+                return Err(CodecError::NonceExhausted.into());
+            };
+            self.decode_nonce = nonce.checked_add(1);
 
-                let mut payload = buf.split_to(size + MAC_SIZE);
+            self.decode_cipher.set_nonce(nonce);
+            self.decode_cipher.decrypt(&mut header);
 
-                self.decode_cipher
-                    .decrypt(payload.get_mut(..size).ok_or_else(|| {
-                        io::Error::new(io::ErrorKind::InvalidData, "payload was malformed")
-                    })?);
-                let mac = payload.split_off(size);
-                self.decode_cipher.check_mac(mac.as_ref())?;
+            let cmd = header[0];
+            let size = u16::from_be_bytes([header[1], header[2]]) as usize;
+            self.decode_state = DecodeState::Payload(cmd, size);
+        }
+
+        if let DecodeState::Payload(cmd, size) = self.decode_state
+            && buf.len() >= size + MAC_SIZE
+        {
+            self.decode_state = DecodeState::Header;
 
-                return Ok(Some((cmd, payload.freeze())));
+            let mut payload = buf.split_to(size + MAC_SIZE);
+
+            self.decode_cipher
+                .decrypt(payload.get_mut(..size).ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "payload was malformed")
+                })?);
+            let mac = payload.split_off(size);
+            if !self.decode_cipher.check_mac(mac.as_ref()) {
+                self.decode_state = DecodeState::Poisoned;
+                return Err(CodecError::MacMismatch.into());
             }
+
+            return Ok(Some(Packet::from_raw(cmd, payload.freeze())));
         }
 
         Ok(None)
@@ -1,7 +1,7 @@
 pub const SPOTIFY_VERSION: u64 = 124200290;
 
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum PacketType {
     SecretBlock = 2,
     Ping = 4,
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use bytes::Bytes;
+use futures_util::{Sink, SinkExt, Stream, StreamExt};
+use log::{trace, warn};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::codec::{CodecError, MAX_PAYLOAD_SIZE};
+use crate::consts::PacketType;
+use crate::error::{Error, Result};
+use crate::packet::Packet;
+
+pub trait PacketHandler: Send + Sync + 'static {
+    fn handle(&self, cmd: PacketType, data: Bytes) -> Result<()>;
+
+    // the session is gone, nothing will be delivered anymore
+    fn close(&self) {}
+}
+
+impl<F> PacketHandler for F
+where
+    F: Fn(PacketType, Bytes) -> Result<()> + Send + Sync + 'static,
+{
+    fn handle(&self, cmd: PacketType, data: Bytes) -> Result<()> {
+        self(cmd, data)
+    }
+}
+
+impl<H: PacketHandler> PacketHandler for Arc<H> {
+    fn handle(&self, cmd: PacketType, data: Bytes) -> Result<()> {
+        (**self).handle(cmd, data)
+    }
+
+    fn close(&self) {
+        (**self).close()
+    }
+}
+
+#[derive(Clone)]
+pub struct PacketSender {
+    tx: mpsc::UnboundedSender<Packet>,
+}
+
+impl PacketSender {
+    pub fn send(&self, cmd: PacketType, data: impl Into<Bytes>) -> Result<()> {
+        self.send_packet(Packet::new(cmd, data))
+    }
+
+    pub fn send_packet(&self, packet: Packet) -> Result<()> {
+        // caught here the caller gets the error, in the codec it would end the session
+        if packet.payload.len() > MAX_PAYLOAD_SIZE {
+            return Err(CodecError::FrameTooLarge(packet.payload.len()).into());
+        }
+        self.tx.send(packet).map_err(|_| Error::SessionClosed)
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.tx.is_closed()
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct HandlerRegistry {
+    handlers: Arc<RwLock<HashMap<PacketType, Arc<dyn PacketHandler>>>>,
+}
+
+impl HandlerRegistry {
+    pub fn register<H: PacketHandler>(&self, kinds: &[PacketType], handler: H) {
+        let handler: Arc<dyn PacketHandler> = Arc::new(handler);
+        let mut handlers = self.handlers.write().unwrap();
+        for kind in kinds {
+            handlers.insert(*kind, handler.clone());
+        }
+    }
+
+    pub fn unregister(&self, kind: PacketType) {
+        self.handlers.write().unwrap().remove(&kind);
+    }
+
+    // removes every handler and closes each of them once
+    pub fn close(&self) {
+        let handlers: Vec<_> = self.handlers.write().unwrap().drain().map(|(_, h)| h).collect();
+        let mut closed: Vec<&Arc<dyn PacketHandler>> = Vec::new();
+        for handler in &handlers {
+            // one handler is usually registered for several packet types
+            if closed.iter().any(|h| Arc::ptr_eq(h, handler)) {
+                continue;
+            }
+            handler.close();
+            closed.push(handler);
+        }
+    }
+
+    fn get(&self, kind: PacketType) -> Option<Arc<dyn PacketHandler>> {
+        self.handlers.read().unwrap().get(&kind).cloned()
+    }
+}
+
+// closes the registry when the task holding it ends, also when it is aborted
+pub(crate) struct CloseOnDrop(pub(crate) HandlerRegistry);
+
+impl Drop for CloseOnDrop {
+    fn drop(&mut self) {
+        self.0.close();
+    }
+}
+
+pub struct Dispatcher {
+    handlers: HandlerRegistry,
+    sender: PacketSender,
+    outgoing: mpsc::UnboundedReceiver<Packet>,
+}
+
+impl Default for Dispatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Dispatcher {
+    pub fn new() -> Dispatcher {
+        let (tx, outgoing) = mpsc::unbounded_channel();
+
+        Dispatcher {
+            handlers: HandlerRegistry::default(),
+            sender: PacketSender { tx },
+            outgoing,
+        }
+    }
+
+    pub fn sender(&self) -> PacketSender {
+        self.sender.clone()
+    }
+
+    pub fn handlers(&self) -> HandlerRegistry {
+        self.handlers.clone()
+    }
+
+    pub fn spawn<S>(self, transport: S) -> JoinHandle<Result<()>>
+    where
+        S: Stream<Item = Result<Packet>> + Sink<Packet, Error = Error> + Send + 'static,
+    {
+        let close = CloseOnDrop(self.handlers());
+        tokio::spawn(async move {
+            let _close = close;
+            self.run(transport).await
+        })
+    }
+
+    pub async fn run<S>(self, transport: S) -> Result<()>
+    where
+        S: Stream<Item = Result<Packet>> + Sink<Packet, Error = Error>,
+    {
+        let Dispatcher {
+            handlers,
+            sender,
+            mut outgoing,
+        } = self;
+        // the writer half should stop once every outside handle is gone
+        drop(sender);
+
+        let (mut sink, mut stream) = transport.split();
+
+        let reader = async {
+            while let Some(packet) = stream.next().await {
+                dispatch(&handlers, packet?);
+            }
+            // only a writer that runs dry is a clean shutdown, the AP never hangs up on purpose
+            Err(Error::ConnectionLost)
+        };
+
+        let writer = async {
+            while let Some(packet) = outgoing.recv().await {
+                sink.send(packet).await?;
+            }
+            sink.close().await
+        };
+
+        tokio::select! {
+            result = reader => result,
+            result = writer => result,
+        }
+    }
+}
+
+#[cfg(test)]
+impl Dispatcher {
+    // what a running dispatcher would write to the transport
+    pub(crate) async fn next_outgoing(&mut self) -> Option<Packet> {
+        self.outgoing.recv().await
+    }
+}
+
+fn dispatch(handlers: &HandlerRegistry, packet: Packet) {
+    let (kind, cmd) = (packet.kind(), packet.cmd());
+    let data = packet.payload;
+    if kind == PacketType::Unknown {
+        warn!("ignoring unknown packet {cmd:#04x} ({} bytes)", data.len());
+        return;
+    }
+
+    match handlers.get(kind) {
+        Some(handler) => {
+            if let Err(e) = handler.handle(kind, data) {
+                warn!("failed to handle {kind:?} packet: {e}");
+            }
+        }
+        None => trace!("no handler for {kind:?} packet ({} bytes)", data.len()),
+    }
+}
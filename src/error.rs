@@ -0,0 +1,91 @@
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::apresolve::ApResolveError;
+use crate::audio_key::AudioKeyError;
+use crate::channel::ChannelError;
+use crate::client::LoginFailure;
+use crate::codec::CodecError;
+use crate::handshake::HandshakeError;
+use crate::mercury::MercuryError;
+use crate::metadata::MetadataError;
+use crate::proxy::ProxyError;
+use crate::spotify_id::SpotifyIdError;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+// connection steps that run under their own deadline
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Phase {
+    Connect,
+    Hello,
+    Response,
+    Login,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Phase::Connect => "tcp connect",
+            Phase::Hello => "client hello",
+            Phase::Response => "client response",
+            Phase::Login => "login",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("transport error: {0}")]
+    Transport(#[from] io::Error),
+    #[error("handshake failed: {0}")]
+    Handshake(#[from] HandshakeError),
+    #[error("codec error: {0}")]
+    Codec(#[from] CodecError),
+    #[error("protobuf error: {0}")]
+    Protobuf(#[from] protobuf::Error),
+    #[error("access point resolve failed: {0}")]
+    ApResolve(#[from] ApResolveError),
+    #[error("mercury error: {0}")]
+    Mercury(#[from] MercuryError),
+    #[error("metadata error: {0}")]
+    Metadata(#[from] MetadataError),
+    #[error("audio key error: {0}")]
+    AudioKey(#[from] AudioKeyError),
+    #[error("channel error: {0}")]
+    Channel(#[from] ChannelError),
+    #[error("proxy error: {0}")]
+    Proxy(#[from] ProxyError),
+    #[error("spotify id error: {0}")]
+    SpotifyId(#[from] SpotifyIdError),
+    #[error("login failed: {0}")]
+    LoginFailed(LoginFailure),
+    #[error("unexpected packet {0:#04x}")]
+    UnexpectedPacket(u8),
+    #[error("malformed packet {0:#04x}")]
+    MalformedPacket(u8),
+    #[error("session closed")]
+    SessionClosed,
+    #[error("connection lost")]
+    ConnectionLost,
+    #[error("{0} timed out")]
+    Timeout(Phase),
+    #[error("credentials cache error: {0}")]
+    Cache(io::Error),
+    #[error("traffic recorder error: {0}")]
+    Recorder(io::Error),
+}
+
+pub(crate) async fn with_timeout<T, F>(phase: Phase, duration: Duration, future: F) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    tokio::time::timeout(duration, future)
+        .await
+        .map_err(|_| Error::Timeout(phase))?
+}
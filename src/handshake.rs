@@ -1,21 +1,33 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
 use hmac::{Hmac, Mac};
-use protobuf::Message;
+use log::debug;
+use protobuf::{EnumOrUnknown, Message};
 use rand::RngCore;
 use rsa::{BigUint, Pkcs1v15Sign, RsaPublicKey};
 use sha1::{Digest, Sha1};
-use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::task;
 use tokio_util::codec::{Decoder, Framed};
 use thiserror::Error;
 
 use crate::consts::SPOTIFY_VERSION;
 use crate::codec::ApCodec;
 use crate::dh::DhLocalKeys;
+use crate::error::{Error, Phase, Result, with_timeout};
+use crate::hashcash;
 
-use crate::protocol;
+use crate::protocol::authentication::{CpuFamily, Os};
 use crate::protocol::keyexchange::{
-    APResponseMessage, ClientHello, ClientResponsePlaintext, Platform, ProductFlags,
+    APResponseMessage, ClientHello, ClientResponsePlaintext, Cryptosuite, Platform,
+    PoWHashCashChallenge, Powscheme, Product, ProductFlags,
 };
 
+// far above anything an AP sends, but keeps a bogus size from allocating gigabytes
+const MAX_HANDSHAKE_PACKET_SIZE: usize = 64 * 1024;
+
 const SERVER_KEY: [u8; 256] = [
     0xac, 0xe0, 0x46, 0x0b, 0xff, 0xc2, 0x30, 0xaf, 0xf4, 0x6b, 0xfe, 0xc3, 0xbf, 0xbf, 0x86, 0x3d,
     0xa1, 0x91, 0xc6, 0xcc, 0x33, 0x6c, 0x93, 0xa1, 0x4f, 0xb3, 0xb0, 0x16, 0x12, 0xac, 0xac, 0x6a,
@@ -41,78 +53,253 @@ pub enum HandshakeError {
     InvalidLength,
     #[error("server key verification failed")]
     VerificationFailed,
+    #[error("client upgrade required")]
+    UpgradeRequired {
+        signed_part: Vec<u8>,
+        http_suffix: Option<String>,
+    },
+    #[error("access point sent no challenge")]
+    MissingChallenge,
+    #[error("proof-of-work challenge could not be solved")]
+    PowFailed,
+    #[error("access point selected cryptosuite {0:?} which was not offered")]
+    UnsupportedCryptosuite(Cryptosuite),
+    #[error("handshake packet of {0} bytes is out of range")]
+    InvalidPacketSize(usize),
 }
 
-pub async fn handshake<T: AsyncRead + AsyncWrite + Unpin>(mut conn: T) -> io::Result<Framed<T, ApCodec>> {
+pub fn server_key() -> RsaPublicKey {
+    let n = BigUint::from_bytes_be(&SERVER_KEY);
+    let e = BigUint::new(vec![65537]);
+    RsaPublicKey::new(n, e).expect("valid server key")
+}
+
+#[derive(Debug, Clone)]
+pub struct HandshakeConfig {
+    product: Product,
+    product_flags: Vec<ProductFlags>,
+    platform: Platform,
+    version: u64,
+    cryptosuites: Vec<Cryptosuite>,
+    padding: Vec<u8>,
+    server_keys: Vec<RsaPublicKey>,
+    pow_budget: Duration,
+    hello_timeout: Duration,
+    response_timeout: Duration,
+}
+
+impl Default for HandshakeConfig {
+    fn default() -> Self {
+        HandshakeConfig {
+            product: Product::PRODUCT_CLIENT,
+            product_flags: vec![ProductFlags::PRODUCT_FLAG_NONE],
+            platform: Platform::PLATFORM_LINUX_X86_64,
+            version: SPOTIFY_VERSION,
+            cryptosuites: vec![Cryptosuite::CRYPTO_SUITE_SHANNON],
+            padding: vec![0x1e],
+            server_keys: vec![server_key()],
+            pow_budget: Duration::from_secs(5),
+            hello_timeout: Duration::from_secs(10),
+            response_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+impl HandshakeConfig {
+    pub fn product(mut self, product: Product) -> Self {
+        self.product = product;
+        self
+    }
+
+    pub fn product_flags(mut self, flags: Vec<ProductFlags>) -> Self {
+        self.product_flags = flags;
+        self
+    }
+
+    pub fn platform(mut self, platform: Platform) -> Self {
+        self.platform = platform;
+        self
+    }
+
+    pub fn version(mut self, version: u64) -> Self {
+        self.version = version;
+        self
+    }
+
+    pub fn cryptosuites(mut self, cryptosuites: Vec<Cryptosuite>) -> Self {
+        self.cryptosuites = cryptosuites;
+        self
+    }
+
+    pub fn padding(mut self, padding: Vec<u8>) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    // replaces the trusted keys, the server signature must verify against one of them
+    pub fn server_keys(mut self, keys: Vec<RsaPublicKey>) -> Self {
+        self.server_keys = keys;
+        self
+    }
+
+    pub fn trust_server_key(mut self, key: RsaPublicKey) -> Self {
+        self.server_keys.push(key);
+        self
+    }
+
+    // how long a proof-of-work challenge may take before the handshake gives up
+    pub fn pow_budget(mut self, budget: Duration) -> Self {
+        self.pow_budget = budget;
+        self
+    }
+
+    // deadline for sending ClientHello and receiving the APResponseMessage
+    pub fn hello_timeout(mut self, timeout: Duration) -> Self {
+        self.hello_timeout = timeout;
+        self
+    }
+
+    // deadline for sending ClientResponsePlaintext
+    pub fn response_timeout(mut self, timeout: Duration) -> Self {
+        self.response_timeout = timeout;
+        self
+    }
+
+    fn verify(&self, data: &[u8], signature: &[u8]) -> Result<()> {
+        let hash = Sha1::digest(data);
+        let trusted = self
+            .server_keys
+            .iter()
+            .any(|key| key.verify(Pkcs1v15Sign::new::<Sha1>(), &hash, signature).is_ok());
+        if !trusted {
+            return Err(HandshakeError::VerificationFailed.into());
+        }
+        Ok(())
+    }
+
+    // the login packet has to describe the same platform the hello announced
+    pub(crate) fn system_info(&self) -> (CpuFamily, Os) {
+        use Platform::*;
+
+        match self.platform {
+            PLATFORM_WIN32_X86 | PLATFORM_WP8_X86 | PLATFORM_WINRT_X86 => (CpuFamily::CPU_X86, Os::OS_WINDOWS),
+            PLATFORM_WIN32_X86_64 | PLATFORM_WINRT_X86_64 => (CpuFamily::CPU_X86_64, Os::OS_WINDOWS),
+            PLATFORM_WINDOWS_CE_ARM => (CpuFamily::CPU_ARM, Os::OS_WINDOWS_CE),
+            PLATFORM_WP7_ARM | PLATFORM_WP8_ARM | PLATFORM_WINRT_ARM => (CpuFamily::CPU_ARM, Os::OS_WP7),
+            PLATFORM_OSX_X86 => (CpuFamily::CPU_X86, Os::OS_OSX),
+            PLATFORM_OSX_X86_64 => (CpuFamily::CPU_X86_64, Os::OS_OSX),
+            PLATFORM_OSX_PPC => (CpuFamily::CPU_PPC, Os::OS_OSX),
+            PLATFORM_IPHONE_ARM | PLATFORM_IPHONE_ARM64 => (CpuFamily::CPU_ARM, Os::OS_IPHONE),
+            PLATFORM_ANDROID_ARM => (CpuFamily::CPU_ARM, Os::OS_ANDROID),
+            PLATFORM_LINUX_X86 => (CpuFamily::CPU_X86, Os::OS_LINUX),
+            PLATFORM_LINUX_X86_64 => (CpuFamily::CPU_X86_64, Os::OS_LINUX),
+            PLATFORM_LINUX_ARM => (CpuFamily::CPU_ARM, Os::OS_LINUX),
+            PLATFORM_LINUX_MIPS => (CpuFamily::CPU_MIPS, Os::OS_LINUX),
+            PLATFORM_LINUX_SH => (CpuFamily::CPU_SH, Os::OS_LINUX),
+            PLATFORM_LINUX_BLACKFIN => (CpuFamily::CPU_BLACKFIN, Os::OS_LINUX),
+            PLATFORM_FREEBSD_X86 => (CpuFamily::CPU_X86, Os::OS_FREEBSD),
+            PLATFORM_FREEBSD_X86_64 => (CpuFamily::CPU_X86_64, Os::OS_FREEBSD),
+            PLATFORM_S60_ARM => (CpuFamily::CPU_ARM, Os::OS_S60),
+            PLATFORM_PALM_ARM => (CpuFamily::CPU_ARM, Os::OS_PALM),
+            PLATFORM_BLACKBERRY_ARM => (CpuFamily::CPU_ARM, Os::OS_BLACKBERRY),
+            PLATFORM_LOGITECH_ARM => (CpuFamily::CPU_ARM, Os::OS_LOGITECH),
+            PLATFORM_ONKYO_ARM => (CpuFamily::CPU_ARM, Os::OS_ONKYO),
+            PLATFORM_QNXNTO_ARM => (CpuFamily::CPU_ARM, Os::OS_QNXNTO),
+            PLATFORM_BCO_ARM => (CpuFamily::CPU_ARM, Os::OS_BCO),
+            PLATFORM_SONOS => (CpuFamily::CPU_UNKNOWN, Os::OS_SONOS),
+            _ => (CpuFamily::CPU_UNKNOWN, Os::OS_UNKNOWN),
+        }
+    }
+}
+
+pub async fn handshake<T: AsyncRead + AsyncWrite + Unpin>(conn: T) -> Result<Framed<T, ApCodec>> {
+    handshake_with_config(conn, &HandshakeConfig::default()).await
+}
+
+pub async fn handshake_with_config<T: AsyncRead + AsyncWrite + Unpin>(
+    mut conn: T,
+    config: &HandshakeConfig,
+) -> Result<Framed<T, ApCodec>> {
     let local_keys = DhLocalKeys::random(&mut rand::rng());
     let public_key = local_keys.public_key();
 
-    let mut accumulator = client_hello(public_key, &mut conn).await?;
-    conn.write_all(&accumulator).await?;
-
-    let message: APResponseMessage = recv_packet(&mut conn, &mut accumulator).await?;
+    let (accumulator, message) = with_timeout(Phase::Hello, config.hello_timeout, async {
+        let mut accumulator = client_hello(config, public_key, &mut conn).await?;
+        let message: APResponseMessage = recv_packet(&mut conn, &mut accumulator).await?;
+        Ok((accumulator, message))
+    })
+    .await?;
+
+    // the AP answers with exactly one of challenge, upgrade or login_failed
+    if let Some(upgrade) = message.upgrade.as_ref() {
+        config.verify(upgrade.upgrade_signed_part(), upgrade.signature())?;
+        return Err(HandshakeError::UpgradeRequired {
+            signed_part: upgrade.upgrade_signed_part().to_owned(),
+            http_suffix: upgrade.http_suffix.clone(),
+        }
+        .into());
+    }
+    if let Some(failed) = message.login_failed.as_ref() {
+        return Err(Error::LoginFailed(failed.clone().into()));
+    }
 
-    let remote_key = message
-        .challenge
-        .get_or_default()
-        .login_crypto_challenge
-        .get_or_default()
-        .diffie_hellman
-        .get_or_default()
-        .gs()
-        .to_owned();
-    let remote_signature = message
+    let diffie_hellman = message
         .challenge
-        .get_or_default()
-        .login_crypto_challenge
-        .get_or_default()
-        .diffie_hellman
-        .get_or_default()
-        .gs_signature()
-        .to_owned();
+        .as_ref()
+        .and_then(|challenge| challenge.login_crypto_challenge.diffie_hellman.as_ref())
+        .ok_or(HandshakeError::MissingChallenge)?;
+    let remote_key = diffie_hellman.gs().to_owned();
+    let remote_signature = diffie_hellman.gs_signature().to_owned();
 
-    let n = BigUint::from_bytes_be(&SERVER_KEY);
-    let e = BigUint::new(vec![65537]);
-    let public_key = RsaPublicKey::new(n, e)
-        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, HandshakeError::VerificationFailed))?;
+    config.verify(&remote_key, &remote_signature)?;
+
+    let suite = select_cryptosuite(config, &message)?;
 
-    let hash = Sha1::digest(&remote_key);
-    let padding = Pkcs1v15Sign::new::<Sha1>();
-    public_key
-        .verify(padding, &hash, &remote_signature)
-        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, HandshakeError::VerificationFailed))?;
+    let pow_suffix = match message.challenge.pow_challenge.hash_cash.as_ref() {
+        Some(challenge) => Some(solve_hash_cash(challenge, config.pow_budget).await?),
+        None => None,
+    };
 
     let shared_secret = local_keys.shared_secret(&remote_key);
     let (challenge, send_key, recv_key) = compute_keys(&shared_secret, &accumulator)?;
-    let codec = ApCodec::new(&send_key, &recv_key);
+    let codec = ApCodec::with_cryptosuite(suite, &send_key, &recv_key)?;
 
-    client_response(&mut conn, challenge).await?;
+    with_timeout(
+        Phase::Response,
+        config.response_timeout,
+        client_response(&mut conn, challenge, pow_suffix, suite),
+    )
+    .await?;
 
     Ok(codec.framed(conn))
 }
 
-async fn client_hello<T: AsyncWrite + Unpin>(public_key: Vec<u8>, conn: &mut T) -> io::Result<Vec<u8>> {
+async fn client_hello<T: AsyncWrite + Unpin>(
+    config: &HandshakeConfig,
+    public_key: Vec<u8>,
+    conn: &mut T,
+) -> Result<Vec<u8>> {
     let mut packet = ClientHello::new();
     packet
         .build_info
         .mut_or_insert_default()
-        .set_product(protocol::keyexchange::Product::PRODUCT_CLIENT);
+        .set_product(config.product);
     packet
         .build_info
         .mut_or_insert_default()
         .product_flags
-        .push(ProductFlags::PRODUCT_FLAG_NONE.into());
+        .extend(config.product_flags.iter().map(|flag| EnumOrUnknown::new(*flag)));
     packet
         .build_info
         .mut_or_insert_default()
-        .set_platform(Platform::PLATFORM_LINUX_X86_64);
+        .set_platform(config.platform);
     packet
         .build_info
         .mut_or_insert_default()
-        .set_version(SPOTIFY_VERSION);
+        .set_version(config.version);
     packet
         .cryptosuites_supported
-        .push(protocol::keyexchange::Cryptosuite::CRYPTO_SUITE_SHANNON.into());
+        .extend(config.cryptosuites.iter().map(|suite| EnumOrUnknown::new(*suite)));
     packet
         .login_crypto_hello
         .mut_or_insert_default()
@@ -130,7 +317,10 @@ async fn client_hello<T: AsyncWrite + Unpin>(public_key: Vec<u8>, conn: &mut T)
     rand::rng().fill_bytes(&mut client_nonce);
 
     packet.set_client_nonce(client_nonce);
-    packet.set_padding(vec![0x1e]);
+    packet
+        .powschemes_supported
+        .push(EnumOrUnknown::new(Powscheme::POW_HASH_CASH));
+    packet.set_padding(config.padding.clone());
 
     let payload_size = packet.compute_size();
     let size = 2 + 4 + payload_size as u32;
@@ -147,7 +337,56 @@ async fn client_hello<T: AsyncWrite + Unpin>(public_key: Vec<u8>, conn: &mut T)
     Ok(buf)
 }
 
-async fn client_response<T: AsyncWrite + Unpin>(conn: &mut T, challenge: Vec<u8>) -> io::Result<()> {
+// the AP answers with the challenge for the suite it picked, Shannon if none
+fn select_cryptosuite(config: &HandshakeConfig, message: &APResponseMessage) -> Result<Cryptosuite> {
+    let suite = if message.challenge.crypto_challenge.rc4_sha1_hmac.is_some() {
+        Cryptosuite::CRYPTO_SUITE_RC4_SHA1_HMAC
+    } else {
+        Cryptosuite::CRYPTO_SUITE_SHANNON
+    };
+    if !config.cryptosuites.contains(&suite) {
+        return Err(HandshakeError::UnsupportedCryptosuite(suite).into());
+    }
+    debug!("using cryptosuite {suite:?}");
+    Ok(suite)
+}
+
+async fn solve_hash_cash(challenge: &PoWHashCashChallenge, budget: Duration) -> Result<Vec<u8>> {
+    let prefix = challenge.prefix().to_owned();
+    let length = challenge.length();
+    let target = challenge.target();
+    if length < 0 {
+        return Err(HandshakeError::PowFailed.into());
+    }
+    debug!("solving hashcash challenge of length {length}");
+
+    // burns CPU for up to `budget`, keep it off the async workers and stop
+    // it when the handshake is dropped
+    let cancel = CancelOnDrop(Arc::new(AtomicBool::new(false)));
+    let flag = cancel.0.clone();
+    let suffix = task::spawn_blocking(move || {
+        hashcash::solve_cancellable(&prefix, length as u32, target, budget, &flag)
+    })
+    .await
+    .map_err(|_| HandshakeError::PowFailed)?;
+
+    Ok(suffix.ok_or(HandshakeError::PowFailed)?.to_vec())
+}
+
+struct CancelOnDrop(Arc<AtomicBool>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+async fn client_response<T: AsyncWrite + Unpin>(
+    conn: &mut T,
+    challenge: Vec<u8>,
+    pow_suffix: Option<Vec<u8>>,
+    suite: Cryptosuite,
+) -> Result<()> {
     let mut packet = ClientResponsePlaintext::new();
     packet
         .login_crypto_response
@@ -156,8 +395,19 @@ async fn client_response<T: AsyncWrite + Unpin>(conn: &mut T, challenge: Vec<u8>
         .mut_or_insert_default()
         .set_hmac(challenge);
 
-    packet.pow_response.mut_or_insert_default();
-    packet.crypto_response.mut_or_insert_default();
+    let pow_response = packet.pow_response.mut_or_insert_default();
+    if let Some(suffix) = pow_suffix {
+        pow_response.hash_cash.mut_or_insert_default().set_hash_suffix(suffix);
+    }
+    let crypto_response = packet.crypto_response.mut_or_insert_default();
+    match suite {
+        Cryptosuite::CRYPTO_SUITE_SHANNON => {
+            crypto_response.shannon.mut_or_insert_default();
+        }
+        Cryptosuite::CRYPTO_SUITE_RC4_SHA1_HMAC => {
+            crypto_response.rc4_sha1_hmac.mut_or_insert_default();
+        }
+    }
 
     let size = 4 + packet.compute_size();
     let mut buff = Vec::with_capacity(size as usize);
@@ -168,13 +418,17 @@ async fn client_response<T: AsyncWrite + Unpin>(conn: &mut T, challenge: Vec<u8>
     Ok(())
 }
 
-async fn recv_packet<T, M>(conn: &mut T, acc: &mut Vec<u8>) -> io::Result<M>
+async fn recv_packet<T, M>(conn: &mut T, acc: &mut Vec<u8>) -> Result<M>
 where
     T: AsyncRead + Unpin,
     M: Message,
 {
     let header = read_into_accumulator(conn, 4, acc).await?;
     let size = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+    // the size includes its own four bytes
+    if !(4..=MAX_HANDSHAKE_PACKET_SIZE).contains(&size) {
+        return Err(HandshakeError::InvalidPacketSize(size).into());
+    }
     let data = read_into_accumulator(conn, size - 4, acc).await?;
     let message = M::parse_from_bytes(data)?;
     Ok(message)
@@ -184,7 +438,7 @@ async fn read_into_accumulator<'b, T: AsyncRead + Unpin>(
     conn: &mut T,
     size: usize,
     acc: &'b mut Vec<u8>,
-) -> io::Result<&'b mut [u8]> {
+) -> Result<&'b mut [u8]> {
     let offset = acc.len();
     acc.resize(offset + size, 0);
 
@@ -192,20 +446,20 @@ async fn read_into_accumulator<'b, T: AsyncRead + Unpin>(
     Ok(&mut acc[offset..])
 }
 
-fn compute_keys(shared_secret: &[u8], packets: &[u8]) -> io::Result<(Vec<u8>, Vec<u8>, Vec<u8>)> {
+pub(crate) fn compute_keys(shared_secret: &[u8], packets: &[u8]) -> Result<(Vec<u8>, Vec<u8>, Vec<u8>)> {
     type HmacSha1 = Hmac<Sha1>;
 
     let mut data = Vec::with_capacity(0x64);
     for i in 1..6 {
         let mut mac = HmacSha1::new_from_slice(shared_secret)
-            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, HandshakeError::InvalidLength))?;
+            .map_err(|_| HandshakeError::InvalidLength)?;
         mac.update(packets);
         mac.update(&[i]);
         data.extend_from_slice(&mac.finalize().into_bytes());
     }
 
     let mut mac = HmacSha1::new_from_slice(&data[..0x14])
-        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, HandshakeError::InvalidLength))?;
+        .map_err(|_| HandshakeError::InvalidLength)?;
     mac.update(packets);
 
     Ok((
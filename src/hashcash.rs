@@ -0,0 +1,59 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use sha1::{Digest, Sha1};
+
+pub const SUFFIX_SIZE: usize = 16;
+
+// how many candidates are tried between two looks at the clock
+const BATCH: u64 = 0x1000;
+
+// Finds a suffix so that the last 64 bits of SHA-1(prefix || suffix) end
+// with at least `length` zero bits. The suffix is the counter offset by
+// `target` followed by the raw counter, both as big endian u64.
+pub fn solve(prefix: &[u8], length: u32, target: i32, budget: Duration) -> Option<[u8; SUFFIX_SIZE]> {
+    solve_cancellable(prefix, length, target, budget, &AtomicBool::new(false))
+}
+
+// like `solve`, but also gives up once `cancel` is set
+pub fn solve_cancellable(
+    prefix: &[u8],
+    length: u32,
+    target: i32,
+    budget: Duration,
+    cancel: &AtomicBool,
+) -> Option<[u8; SUFFIX_SIZE]> {
+    if length > u64::BITS {
+        return None;
+    }
+
+    let started = Instant::now();
+    let mut counter: u64 = 0;
+    loop {
+        for _ in 0..BATCH {
+            let mut suffix = [0u8; SUFFIX_SIZE];
+            suffix[..8].copy_from_slice(&(target as u64).wrapping_add(counter).to_be_bytes());
+            suffix[8..].copy_from_slice(&counter.to_be_bytes());
+
+            if verify(prefix, &suffix, length) {
+                return Some(suffix);
+            }
+            counter = counter.wrapping_add(1);
+        }
+
+        if started.elapsed() >= budget || cancel.load(Ordering::Relaxed) {
+            return None;
+        }
+    }
+}
+
+pub fn verify(prefix: &[u8], suffix: &[u8], length: u32) -> bool {
+    let mut hasher = Sha1::new();
+    hasher.update(prefix);
+    hasher.update(suffix);
+    let digest = hasher.finalize();
+
+    let mut tail = [0u8; 8];
+    tail.copy_from_slice(&digest[12..20]);
+    u64::from_be_bytes(tail).trailing_zeros() >= length
+}
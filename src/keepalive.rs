@@ -0,0 +1,65 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use bytes::Bytes;
+use log::trace;
+use tokio::time::{self, Instant};
+
+use crate::consts::PacketType;
+use crate::dispatch::{PacketHandler, PacketSender};
+use crate::error::{Error, Result};
+
+pub const DEFAULT_PING_TIMEOUT: Duration = Duration::from_secs(150);
+
+pub struct KeepAlive {
+    sender: PacketSender,
+    last_ping: Mutex<Instant>,
+    last_pong_ack: Mutex<Option<Instant>>,
+}
+
+impl KeepAlive {
+    pub fn new(sender: PacketSender) -> KeepAlive {
+        KeepAlive {
+            sender,
+            last_ping: Mutex::new(Instant::now()),
+            last_pong_ack: Mutex::new(None),
+        }
+    }
+
+    pub fn last_ping(&self) -> Instant {
+        *self.last_ping.lock().unwrap()
+    }
+
+    pub fn last_pong_ack(&self) -> Option<Instant> {
+        *self.last_pong_ack.lock().unwrap()
+    }
+
+    // resolves once no Ping has arrived for `timeout`
+    pub async fn watch(&self, timeout: Duration) -> Error {
+        loop {
+            let deadline = self.last_ping() + timeout;
+            if deadline <= Instant::now() {
+                return Error::ConnectionLost;
+            }
+            time::sleep_until(deadline).await;
+        }
+    }
+}
+
+impl PacketHandler for KeepAlive {
+    fn handle(&self, cmd: PacketType, _data: Bytes) -> Result<()> {
+        match cmd {
+            PacketType::Ping => {
+                trace!("ping received, sending pong");
+                *self.last_ping.lock().unwrap() = Instant::now();
+                self.sender.send(PacketType::Pong, vec![0, 0, 0, 0])
+            }
+            PacketType::PongAck => {
+                trace!("pong acknowledged");
+                *self.last_pong_ack.lock().unwrap() = Some(Instant::now());
+                Ok(())
+            }
+            _ => Err(Error::UnexpectedPacket(cmd as u8)),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+pub mod apresolve;
+pub mod audio_decrypt;
+pub mod audio_key;
+pub mod cache;
+pub mod channel;
+pub mod client;
+pub mod codec;
+pub mod consts;
+pub mod dh;
+pub mod dispatch;
+pub mod error;
+pub mod handshake;
+pub mod hashcash;
+pub mod keepalive;
+pub mod mercury;
+pub mod metadata;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod packet;
+pub mod protocol;
+pub mod proxy;
+pub mod recorder;
+pub mod session_info;
+pub mod spotify_id;
+
+pub use apresolve::{AccessPoint, ApResolveData, ApResolveError, ApResolver};
+pub use audio_decrypt::AudioDecrypt;
+pub use audio_key::{AudioKey, AudioKeyError, AudioKeyManager};
+pub use cache::CredentialsCache;
+pub use channel::{Channel, ChannelError, ChannelManager};
+pub use client::{Credentials, LoginFailure, Session, SessionConfig, Welcome};
+pub use codec::{ApCodec, CodecError, FrameCipher, Rc4HmacCipher};
+pub use consts::PacketType;
+pub use dh::DhLocalKeys;
+pub use dispatch::{Dispatcher, HandlerRegistry, PacketHandler, PacketSender};
+pub use error::{Error, Phase, Result};
+pub use handshake::{HandshakeConfig, HandshakeError, handshake};
+pub use mercury::{MercuryError, MercuryManager, MercuryMessage, MercuryResponse, MercurySubscription};
+pub use metadata::{
+    Album, AlbumType, Artist, AudioFileFormat, Episode, Metadata, MetadataError, MetadataItem, Restriction,
+    RestrictionCatalogue, Show, Track,
+};
+pub use packet::{Packet, PacketPayload};
+pub use proxy::{Proxy, ProxyError, ProxyScheme};
+pub use recorder::{Direction, Record, RecordReader, Recorded, Recorder};
+pub use session_info::{SessionInfo, SessionInfoHandler, UserAttributes};
+pub use spotify_id::{FileId, SpotifyId, SpotifyIdError, SpotifyItemType};
@@ -1,101 +1,118 @@
-mod protocol;
-mod consts;
-mod http;
-mod dh;
-mod client;
-mod codec;
-mod handshake;
-// use futures_util::{SinkExt, StreamExt};
-// use protobuf::Message;
+use std::path::PathBuf;
+use std::time::Duration;
 
-// use crate::protocol::authentication::{APWelcome, ClientResponseEncrypted, CpuFamily, Os};
+use fyspoti::recorder::describe;
+use fyspoti::{
+    Credentials, CredentialsCache, Direction, Proxy, RecordReader, Result, Session, SessionConfig,
+};
 
-async fn conn() -> Result<(), Box<dyn std::error::Error>> {
-    let data = http::reqwest_ap_resolve_data().await?;
-    if data.accesspoint.is_empty() {
-        panic!("empty accesspoint list");
+fn cache_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os("FYSPOTI_CACHE_DIR") {
+        return dir.into();
     }
+    let home = std::env::var_os("HOME").unwrap_or_else(|| ".".into());
+    PathBuf::from(home).join(".cache").join("fyspoti")
+}
 
-    println!("{:#?}", data);
-
-    let (host, port) = data
-        .accesspoint_4070()
-        .next()
-        .unwrap();
-
-    let stream = tokio::net::TcpStream::connect((host, port)).await?;
-    let transport = handshake::handshake(stream).await?;
-
-    // let device_id = uuid::Uuid::new_v4().as_hyphenated().to_string();
-
-    // let mut packet = ClientResponseEncrypted::new();
-    // if let Some(username) = credentials.username {
-    //     packet
-    //         .login_credentials
-    //         .mut_or_insert_default()
-    //         .set_username(username);
-    // }
-    // packet
-    //     .login_credentials
-    //     .mut_or_insert_default()
-    //     .set_typ(credentials.auth_type);
-    // packet
-    //     .login_credentials
-    //     .mut_or_insert_default()
-    //     .set_auth_data(credentials.auth_data);
-    // packet
-    //     .system_info
-    //     .mut_or_insert_default()
-    //     .set_cpu_family(CpuFamily::CPU_X86_64);
-    // packet.system_info.mut_or_insert_default().set_os(Os::OS_LINUX);
-    // // packet
-    // //     .system_info
-    // //     .mut_or_insert_default()
-    // //     .set_system_information_string("".into());
-    // packet
-    //     .system_info
-    //     .mut_or_insert_default()
-    //     .set_device_id(device_id);
-    // // packet.set_version_string("".into());
+fn inspect(path: &str) -> Result<()> {
+    for record in RecordReader::open(path)? {
+        let record = record?;
+        let timestamp = record
+            .timestamp
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default();
+        let arrow = match record.direction {
+            Direction::Inbound => "<-",
+            Direction::Outbound => "->",
+        };
+        let packet = &record.packet;
+        println!(
+            "{}.{:06} {arrow} {:?} ({:#04x}) {} bytes",
+            timestamp.as_secs(),
+            timestamp.subsec_micros(),
+            packet.kind(),
+            packet.cmd(),
+            packet.payload.len()
+        );
+        match describe(packet) {
+            Some(text) => {
+                for line in text.lines() {
+                    println!("    {line}");
+                }
+            }
+            None if !packet.payload.is_empty() => {
+                let shown = &packet.payload[..packet.payload.len().min(32)];
+                println!("    {shown:02x?}");
+            }
+            None => {}
+        }
+    }
+    Ok(())
+}
 
-    // let cmd = consts::PacketType::Login;
-    // let data = packet.write_to_bytes()?;
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let Some(username) = args.next() else {
+        eprintln!("usage: fyspoti <username> [password]");
+        eprintln!("       fyspoti inspect <recording>");
+        std::process::exit(2);
+    };
 
-    // transport.send((cmd as u8, data)).await?;
+    if username == "inspect" {
+        let Some(path) = args.next() else {
+            eprintln!("usage: fyspoti inspect <recording>");
+            std::process::exit(2);
+        };
+        return inspect(&path);
+    }
 
-    // let (cmd, data) = transport
-    //     .next()
-    //     .await
-    //     .ok_or("auth error")??;
-    // let packet_type = consts::PacketType::from(cmd);
-    // let result = match packet_type {
-    //     consts::PacketType::APWelcome => {
-    //         let welcome_data = APWelcome::parse_from_bytes(data.as_ref())?;
+    let cache = CredentialsCache::new(cache_dir());
+    let credentials = match args.next() {
+        Some(password) => Credentials::with_password(username.clone(), password),
+        None => match cache.load(&username)? {
+            Some(credentials) => credentials,
+            None => {
+                eprintln!("no cached credentials for {username}, a password is required");
+                std::process::exit(2);
+            }
+        },
+    };
 
-    //         let reusable_credentials = Credentials {
-    //             username: Some(welcome_data.canonical_username().to_owned()),
-    //             auth_type: welcome_data.reusable_auth_credentials_type(),
-    //             auth_data: welcome_data.reusable_auth_credentials().to_owned(),
-    //         };
+    let proxy = match std::env::var("FYSPOTI_PROXY") {
+        Ok(url) => Some(url.parse::<Proxy>()?),
+        Err(_) => None,
+    };
+    let config = SessionConfig {
+        record: std::env::var_os("FYSPOTI_RECORD").map(PathBuf::from),
+        proxy,
+        ..SessionConfig::default()
+    };
+    let session = Session::connect_with_config(config, credentials).await?;
+    let welcome = session.welcome();
+    println!("logged in as {} ({:?})", welcome.canonical_username, welcome.account_type);
 
-    //         Ok(reusable_credentials)
-    //     }
-    //     consts::PacketType::AuthFailure => {
-    //         let error_data = APLoginFailed::parse_from_bytes(data.as_ref())?;
-    //         Err(error_data.into())
-    //     }
-    //     _ => {
-    //         // trace!("Did not expect {cmd:?} AES key packet with data {data:#?}");
-    //         Err(AuthenticationError::Packet(cmd))
-    //     }
-    // };
+    match tokio::time::timeout(Duration::from_secs(5), session.wait_for_attributes()).await {
+        Ok(Ok(info)) => println!(
+            "account type {}, country {}",
+            info.account_type().unwrap_or("unknown"),
+            info.country.as_deref().unwrap_or("unknown")
+        ),
+        Ok(Err(e)) => return Err(e),
+        Err(_) => println!("no product info received"),
+    }
 
-    Ok(())
-}
+    cache.save(&welcome.reusable_credentials)?;
+    if username != welcome.canonical_username {
+        cache.save_as(&username, &welcome.reusable_credentials)?;
+    }
+    println!(
+        "credentials for {} cached in {}",
+        welcome.canonical_username,
+        cache.dir().display()
+    );
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    conn().await?;
+    session.shutdown();
 
     Ok(())
 }
@@ -0,0 +1,468 @@
+use std::collections::HashMap;
+use std::mem;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures_util::Stream;
+use log::{debug, warn};
+use thiserror::Error;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::consts::PacketType;
+use crate::dispatch::{PacketHandler, PacketSender};
+use crate::error::{Error, Result};
+
+mod types;
+
+pub use types::{MercuryMessage, MercuryMethod, MercuryRequest, MercuryResponse};
+use types::{read_bytes, read_u16};
+
+pub const DEFAULT_MERCURY_TIMEOUT: Duration = Duration::from_secs(15);
+
+const FLAG_FINAL: u8 = 0x01;
+const FLAG_PARTIAL: u8 = 0x02;
+
+#[derive(Debug, Error)]
+pub enum MercuryError {
+    #[error("malformed mercury packet")]
+    Malformed,
+    #[error("mercury part of {0} bytes is too large")]
+    PartTooLarge(usize),
+    #[error("too many mercury parts ({0})")]
+    TooManyParts(usize),
+    #[error("mercury request of {0} bytes does not fit in a frame")]
+    RequestTooLarge(usize),
+    #[error("mercury request was cancelled")]
+    Cancelled,
+    #[error("mercury subscription rejected with status {0}")]
+    SubscriptionRejected(i32),
+    #[error("mercury request timed out")]
+    Timeout,
+}
+
+#[derive(Default)]
+struct MercuryPending {
+    parts: Vec<Vec<u8>>,
+    partial: Option<Vec<u8>>,
+    callback: Option<oneshot::Sender<Result<MercuryResponse>>>,
+}
+
+struct MercurySubscriber {
+    id: u64,
+    uri: String,
+    tx: mpsc::UnboundedSender<MercuryMessage>,
+}
+
+#[derive(Default)]
+struct MercuryState {
+    sequence: u64,
+    pending: HashMap<Vec<u8>, MercuryPending>,
+    subscription_id: u64,
+    subscriptions: Vec<MercurySubscriber>,
+}
+
+struct MercuryInner {
+    sender: PacketSender,
+    state: Mutex<MercuryState>,
+}
+
+#[derive(Clone)]
+pub struct MercuryManager(Arc<MercuryInner>);
+
+impl MercuryManager {
+    pub fn new(sender: PacketSender) -> MercuryManager {
+        MercuryManager(Arc::new(MercuryInner {
+            sender,
+            state: Mutex::new(MercuryState::default()),
+        }))
+    }
+
+    fn next_seq(&self) -> Vec<u8> {
+        let mut state = self.0.state.lock().unwrap();
+        let seq = state.sequence;
+        state.sequence += 1;
+        seq.to_be_bytes().to_vec()
+    }
+
+    fn start_request(
+        &self,
+        req: &MercuryRequest,
+        callback: Option<oneshot::Sender<Result<MercuryResponse>>>,
+    ) -> Result<Vec<u8>> {
+        let seq = self.next_seq();
+        let data = req.encode(&seq)?;
+
+        let pending = MercuryPending {
+            callback,
+            ..Default::default()
+        };
+        self.0.state.lock().unwrap().pending.insert(seq.clone(), pending);
+
+        if let Err(e) = self.0.sender.send(req.method.command(), data) {
+            self.0.state.lock().unwrap().pending.remove(&seq);
+            return Err(e);
+        }
+        Ok(seq)
+    }
+
+    pub async fn request(&self, req: MercuryRequest) -> Result<MercuryResponse> {
+        self.request_with_timeout(req, DEFAULT_MERCURY_TIMEOUT).await
+    }
+
+    pub async fn request_with_timeout(&self, req: MercuryRequest, timeout: Duration) -> Result<MercuryResponse> {
+        let (tx, rx) = oneshot::channel();
+        let seq = self.start_request(&req, Some(tx))?;
+        // dropped on every exit path, including cancellation of this future
+        let _pending = PendingGuard { manager: self, seq };
+
+        match tokio::time::timeout(timeout, rx).await {
+            Ok(Ok(reply)) => reply,
+            Ok(Err(_)) => Err(MercuryError::Cancelled.into()),
+            Err(_) => Err(MercuryError::Timeout.into()),
+        }
+    }
+
+    pub async fn get(&self, uri: impl Into<String>) -> Result<MercuryResponse> {
+        self.request(MercuryRequest::new(MercuryMethod::Get, uri)).await
+    }
+
+    pub async fn send(&self, uri: impl Into<String>, data: Vec<u8>) -> Result<MercuryResponse> {
+        let mut req = MercuryRequest::new(MercuryMethod::Send, uri);
+        req.payload.push(data);
+        self.request(req).await
+    }
+
+    pub async fn post(&self, uri: impl Into<String>, data: Vec<u8>) -> Result<MercuryResponse> {
+        let mut req = MercuryRequest::new(MercuryMethod::Post, uri);
+        req.payload.push(data);
+        self.request(req).await
+    }
+
+    pub async fn subscribe(&self, uri: impl Into<String>) -> Result<MercurySubscription> {
+        let uri = uri.into();
+
+        // register first, events may arrive right behind the SUB reply
+        let (tx, rx) = mpsc::unbounded_channel();
+        let id = {
+            let mut state = self.0.state.lock().unwrap();
+            let id = state.subscription_id;
+            state.subscription_id += 1;
+            state.subscriptions.push(MercurySubscriber {
+                id,
+                uri: uri.clone(),
+                tx,
+            });
+            id
+        };
+        let subscription = MercurySubscription {
+            id,
+            uri,
+            rx,
+            manager: self.clone(),
+        };
+
+        let response = self
+            .request(MercuryRequest::new(MercuryMethod::Sub, subscription.uri.clone()))
+            .await?;
+        if !response.is_success() {
+            return Err(MercuryError::SubscriptionRejected(response.status_code).into());
+        }
+
+        Ok(subscription)
+    }
+
+    fn unsubscribe(&self, id: u64, uri: &str) {
+        let still_subscribed = {
+            let mut state = self.0.state.lock().unwrap();
+            state.subscriptions.retain(|sub| sub.id != id);
+            state.subscriptions.iter().any(|sub| sub.uri == uri)
+        };
+        if still_subscribed {
+            return;
+        }
+
+        // nobody waits for the reply, it is consumed by the pending entry
+        let req = MercuryRequest::new(MercuryMethod::Unsub, uri);
+        if let Err(e) = self.start_request(&req, None) {
+            debug!("failed to unsubscribe from {uri}: {e}");
+        }
+    }
+
+    fn dispatch(&self, cmd: PacketType, mut data: Bytes) -> Result<()> {
+        let seq_len = read_u16(&mut data)? as usize;
+        let seq = read_bytes(&mut data, seq_len)?.to_vec();
+        let flags = read_bytes(&mut data, 1)?[0];
+        let count = read_u16(&mut data)? as usize;
+
+        let pending = self.0.state.lock().unwrap().pending.remove(&seq);
+        let mut pending = match pending {
+            Some(pending) => pending,
+            // server pushed events carry a sequence of their own
+            None if cmd == PacketType::MercuryEvent => MercuryPending::default(),
+            None => {
+                warn!("ignoring {cmd:?} for unknown sequence {seq:02x?}");
+                return Ok(());
+            }
+        };
+
+        if let Err(e) = read_parts(&mut pending, flags, count, &mut data) {
+            // the rest of the response is lost, no point in waiting for the timeout
+            if let Some(callback) = pending.callback {
+                let _ = callback.send(Err(MercuryError::Malformed.into()));
+            }
+            return Err(e);
+        }
+
+        if flags == FLAG_FINAL {
+            self.complete(cmd, pending)?;
+        } else {
+            self.0.state.lock().unwrap().pending.insert(seq, pending);
+        }
+
+        Ok(())
+    }
+
+    fn complete(&self, cmd: PacketType, pending: MercuryPending) -> Result<()> {
+        if cmd == PacketType::MercuryEvent {
+            let message = MercuryMessage::from_parts(pending.parts)?;
+            let mut state = self.0.state.lock().unwrap();
+            let mut delivered = false;
+            state.subscriptions.retain(|sub| {
+                if !message.uri.starts_with(&sub.uri) {
+                    return true;
+                }
+                delivered = true;
+                sub.tx.send(message.clone()).is_ok()
+            });
+            if !delivered {
+                debug!("no subscriber for mercury event {}", message.uri);
+            }
+        } else if let Some(callback) = pending.callback {
+            let _ = callback.send(MercuryResponse::from_parts(pending.parts));
+        }
+        Ok(())
+    }
+}
+
+fn read_parts(pending: &mut MercuryPending, flags: u8, count: usize, data: &mut Bytes) -> Result<()> {
+    for i in 0..count {
+        let size = read_u16(data)? as usize;
+        let mut part = read_bytes(data, size)?.to_vec();
+
+        // a part that was cut at the end of the previous frame continues here
+        if let Some(mut partial) = mem::take(&mut pending.partial) {
+            partial.extend_from_slice(&part);
+            part = partial;
+        }
+
+        if i == count - 1 && flags == FLAG_PARTIAL {
+            pending.partial = Some(part);
+        } else {
+            pending.parts.push(part);
+        }
+    }
+    Ok(())
+}
+
+struct PendingGuard<'a> {
+    manager: &'a MercuryManager,
+    seq: Vec<u8>,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        self.manager.0.state.lock().unwrap().pending.remove(&self.seq);
+    }
+}
+
+pub struct MercurySubscription {
+    id: u64,
+    uri: String,
+    rx: mpsc::UnboundedReceiver<MercuryMessage>,
+    manager: MercuryManager,
+}
+
+impl MercurySubscription {
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+}
+
+impl Stream for MercurySubscription {
+    type Item = MercuryMessage;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+impl Drop for MercurySubscription {
+    fn drop(&mut self) {
+        self.manager.unsubscribe(self.id, &self.uri);
+    }
+}
+
+impl PacketHandler for MercuryManager {
+    fn handle(&self, cmd: PacketType, data: Bytes) -> Result<()> {
+        self.dispatch(cmd, data)
+    }
+
+    fn close(&self) {
+        let pending = {
+            let mut state = self.0.state.lock().unwrap();
+            // dropping the senders ends every subscription stream
+            state.subscriptions.clear();
+            mem::take(&mut state.pending)
+        };
+        for callback in pending.into_values().filter_map(|pending| pending.callback) {
+            let _ = callback.send(Err(Error::SessionClosed));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BufMut;
+    use futures_util::FutureExt;
+    use protobuf::Message;
+
+    use super::*;
+    use crate::dispatch::Dispatcher;
+    use crate::protocol::mercury::Header;
+
+    // the first request of a fresh manager
+    const SEQ: [u8; 8] = [0; 8];
+
+    fn frame(seq: &[u8], flags: u8, parts: &[&[u8]]) -> Bytes {
+        let mut data = Vec::new();
+        data.put_u16(seq.len() as u16);
+        data.extend_from_slice(seq);
+        data.put_u8(flags);
+        data.put_u16(parts.len() as u16);
+        for part in parts {
+            data.put_u16(part.len() as u16);
+            data.extend_from_slice(part);
+        }
+        data.into()
+    }
+
+    fn header(uri: &str, status_code: i32) -> Vec<u8> {
+        let mut header = Header::new();
+        header.set_uri(uri.to_owned());
+        header.set_status_code(status_code);
+        header.write_to_bytes().unwrap()
+    }
+
+    #[test]
+    fn encode_layout() {
+        let mut req = MercuryRequest::new(MercuryMethod::Get, "hm://test/item");
+        req.payload.push(b"body".to_vec());
+        let data = req.encode(&[0, 0, 0, 0, 0, 0, 0, 7]).unwrap();
+
+        assert_eq!(data[..2], [0, 8]);
+        assert_eq!(data[2..10], [0, 0, 0, 0, 0, 0, 0, 7]);
+        assert_eq!(data[10], FLAG_FINAL);
+        assert_eq!(data[11..13], [0, 2]);
+
+        let header_len = u16::from_be_bytes([data[13], data[14]]) as usize;
+        let header = Header::parse_from_bytes(&data[15..15 + header_len]).unwrap();
+        assert_eq!((header.uri(), header.method()), ("hm://test/item", "GET"));
+        assert_eq!(data[15 + header_len..], *b"\x00\x04body");
+    }
+
+    #[tokio::test]
+    async fn response_across_frames() {
+        let mut dispatcher = Dispatcher::new();
+        let manager = MercuryManager::new(dispatcher.sender());
+
+        let request = tokio::spawn({
+            let manager = manager.clone();
+            async move { manager.get("hm://test/item").await }
+        });
+        let sent = dispatcher.next_outgoing().await.unwrap();
+        assert_eq!(sent.kind(), PacketType::MercuryReq);
+
+        // the body part is cut at the end of the first frame
+        let header = header("hm://test/item", 200);
+        let first = frame(&SEQ, FLAG_PARTIAL, &[&header, b"hel"]);
+        manager.handle(PacketType::MercuryReq, first).unwrap();
+        manager.handle(PacketType::MercuryReq, frame(&SEQ, FLAG_FINAL, &[b"lo", b"world"])).unwrap();
+
+        let response = request.await.unwrap().unwrap();
+        assert_eq!(response.uri, "hm://test/item");
+        assert!(response.is_success());
+        assert_eq!(response.payload, [b"hello".to_vec(), b"world".to_vec()]);
+    }
+
+    #[tokio::test]
+    async fn malformed_frame_fails_request() {
+        let mut dispatcher = Dispatcher::new();
+        let manager = MercuryManager::new(dispatcher.sender());
+
+        let request = tokio::spawn({
+            let manager = manager.clone();
+            async move { manager.get("hm://test/item").await }
+        });
+        dispatcher.next_outgoing().await.unwrap();
+
+        // announces two parts but carries one
+        let mut data = frame(&SEQ, FLAG_FINAL, &[&header("hm://test/item", 200)]).to_vec();
+        data[12] = 2;
+        assert!(manager.handle(PacketType::MercuryReq, data.into()).is_err());
+
+        let result = request.await.unwrap();
+        assert!(matches!(result, Err(Error::Mercury(MercuryError::Malformed))));
+    }
+
+    // answers the SUB request the way the AP does
+    async fn subscribe(dispatcher: &mut Dispatcher, manager: &MercuryManager, uri: &str) -> MercurySubscription {
+        let subscription = tokio::spawn({
+            let (manager, uri) = (manager.clone(), uri.to_owned());
+            async move { manager.subscribe(uri).await }
+        });
+        let sent = dispatcher.next_outgoing().await.unwrap();
+        assert_eq!(sent.kind(), PacketType::MercurySub);
+        let reply = frame(&sent.payload[2..10], FLAG_FINAL, &[&header(uri, 200)]);
+        manager.handle(PacketType::MercurySub, reply).unwrap();
+        subscription.await.unwrap().unwrap()
+    }
+
+    #[tokio::test]
+    async fn events_routed_by_uri_prefix() {
+        let mut dispatcher = Dispatcher::new();
+        let manager = MercuryManager::new(dispatcher.sender());
+        let mut user = subscribe(&mut dispatcher, &manager, "hm://pusher/v1/user/alice/").await;
+        let mut other = subscribe(&mut dispatcher, &manager, "hm://pusher/v1/user/bob/").await;
+
+        let event = frame(b"event", FLAG_FINAL, &[&header("hm://pusher/v1/user/alice/playlists", 200), b"x"]);
+        manager.handle(PacketType::MercuryEvent, event).unwrap();
+
+        let message = user.rx.try_recv().unwrap();
+        assert_eq!(message.uri, "hm://pusher/v1/user/alice/playlists");
+        assert_eq!(message.payload, [b"x".to_vec()]);
+        assert!(other.rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn last_subscriber_unsubscribes() {
+        let mut dispatcher = Dispatcher::new();
+        let manager = MercuryManager::new(dispatcher.sender());
+        let first = subscribe(&mut dispatcher, &manager, "hm://test/feed").await;
+        let second = subscribe(&mut dispatcher, &manager, "hm://test/feed").await;
+
+        // still in use by the second subscription
+        drop(first);
+        assert!(dispatcher.next_outgoing().now_or_never().is_none());
+        drop(second);
+        let sent = dispatcher.next_outgoing().await.unwrap();
+        assert_eq!(sent.kind(), PacketType::MercuryUnsub);
+        let mut data = sent.payload.slice(13..);
+        let header_len = read_u16(&mut data).unwrap() as usize;
+        let header = Header::parse_from_bytes(&data[..header_len]).unwrap();
+        assert_eq!((header.uri(), header.method()), ("hm://test/feed", "UNSUB"));
+        assert!(manager.0.state.lock().unwrap().subscriptions.is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt;
+
+use bytes::{BufMut, Bytes};
+use protobuf::Message;
+
+use crate::codec::MAX_PAYLOAD_SIZE;
+use crate::consts::PacketType;
+use crate::error::Result;
+use crate::protocol::mercury::{Header, UserField};
+
+use super::MercuryError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MercuryMethod {
+    Get,
+    Sub,
+    Unsub,
+    Send,
+    Post,
+}
+
+impl MercuryMethod {
+    pub fn command(&self) -> PacketType {
+        match self {
+            MercuryMethod::Get | MercuryMethod::Send | MercuryMethod::Post => PacketType::MercuryReq,
+            MercuryMethod::Sub => PacketType::MercurySub,
+            MercuryMethod::Unsub => PacketType::MercuryUnsub,
+        }
+    }
+}
+
+impl fmt::Display for MercuryMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            MercuryMethod::Get => "GET",
+            MercuryMethod::Sub => "SUB",
+            MercuryMethod::Unsub => "UNSUB",
+            MercuryMethod::Send => "SEND",
+            MercuryMethod::Post => "POST",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MercuryRequest {
+    pub method: MercuryMethod,
+    pub uri: String,
+    pub content_type: Option<String>,
+    pub headers: Vec<(String, Vec<u8>)>,
+    pub payload: Vec<Vec<u8>>,
+}
+
+impl MercuryRequest {
+    pub fn new(method: MercuryMethod, uri: impl Into<String>) -> MercuryRequest {
+        MercuryRequest {
+            method,
+            uri: uri.into(),
+            content_type: None,
+            headers: Vec::new(),
+            payload: Vec::new(),
+        }
+    }
+
+    // +---------+-----+-------+-------+--------+-------+
+    // | SEQ_LEN | SEQ | FLAGS | COUNT | HEADER | PARTS |
+    // +---------+-----+-------+-------+--------+-------+
+    // | u16     | N   | u8    | u16   | part   | parts |
+    // +---------+-----+-------+-------+--------+-------+
+    // Every part is a u16 length followed by its bytes,
+    // the first one is always the protobuf `Header`.
+    pub fn encode(&self, seq: &[u8]) -> Result<Vec<u8>> {
+        let mut header = Header::new();
+        header.set_uri(self.uri.clone());
+        header.set_method(self.method.to_string());
+        if let Some(content_type) = &self.content_type {
+            header.set_content_type(content_type.clone());
+        }
+        for (key, value) in &self.headers {
+            let mut field = UserField::new();
+            field.set_key(key.clone());
+            field.set_value(value.clone());
+            header.user_fields.push(field);
+        }
+        let header = header.write_to_bytes()?;
+
+        let count = 1 + self.payload.len();
+        if count > u16::MAX as usize {
+            return Err(MercuryError::TooManyParts(count).into());
+        }
+
+        let mut packet = Vec::new();
+        packet.put_u16(seq.len() as u16);
+        packet.extend_from_slice(seq);
+        packet.put_u8(1); // FINAL
+        packet.put_u16(count as u16);
+        for part in std::iter::once(&header).chain(&self.payload) {
+            if part.len() > u16::MAX as usize {
+                return Err(MercuryError::PartTooLarge(part.len()).into());
+            }
+            packet.put_u16(part.len() as u16);
+            packet.extend_from_slice(part);
+        }
+        // requests are always sent as a single FINAL frame
+        if packet.len() > MAX_PAYLOAD_SIZE {
+            return Err(MercuryError::RequestTooLarge(packet.len()).into());
+        }
+
+        Ok(packet)
+    }
+}
+
+// subscription events share the response layout
+pub type MercuryMessage = MercuryResponse;
+
+#[derive(Debug, Clone)]
+pub struct MercuryResponse {
+    pub uri: String,
+    pub status_code: i32,
+    pub content_type: Option<String>,
+    pub headers: Vec<(String, Vec<u8>)>,
+    pub payload: Vec<Vec<u8>>,
+}
+
+impl MercuryResponse {
+    pub(crate) fn from_parts(mut parts: Vec<Vec<u8>>) -> Result<MercuryResponse> {
+        if parts.is_empty() {
+            return Err(MercuryError::Malformed.into());
+        }
+        let header = Header::parse_from_bytes(&parts.remove(0))?;
+
+        Ok(MercuryResponse {
+            uri: header.uri().to_owned(),
+            status_code: header.status_code(),
+            content_type: header.content_type.clone(),
+            headers: header
+                .user_fields
+                .iter()
+                .map(|field| (field.key().to_owned(), field.value().to_owned()))
+                .collect(),
+            payload: parts,
+        })
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status_code)
+    }
+
+    pub fn header(&self, key: &str) -> Option<&[u8]> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_slice())
+    }
+}
+
+pub(crate) fn read_u16(data: &mut Bytes) -> Result<u16> {
+    let bytes = read_bytes(data, 2)?;
+    Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+pub(crate) fn read_bytes(data: &mut Bytes, len: usize) -> Result<Bytes> {
+    if data.len() < len {
+        return Err(MercuryError::Malformed.into());
+    }
+    Ok(data.split_to(len))
+}
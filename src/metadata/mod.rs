@@ -0,0 +1,59 @@
+use log::debug;
+use protobuf::Message;
+use thiserror::Error;
+
+use crate::client::Session;
+use crate::error::Result;
+use crate::spotify_id::{SpotifyId, SpotifyItemType};
+
+mod types;
+
+pub use types::{
+    Album, AlbumType, Artist, AudioFileFormat, Date, Episode, Restriction, RestrictionCatalogue, Show, Track,
+};
+
+#[derive(Debug, Error)]
+pub enum MetadataError {
+    #[error("metadata request failed with status {0}")]
+    Status(i32),
+    #[error("metadata response has no payload")]
+    Empty,
+    #[error("expected a {expected} id, got {actual}")]
+    WrongItemType {
+        expected: SpotifyItemType,
+        actual: SpotifyItemType,
+    },
+}
+
+pub trait MetadataItem: Sized {
+    const ITEM_TYPE: SpotifyItemType;
+    type Message: Message;
+
+    fn from_message(id: SpotifyId, msg: Self::Message) -> Self;
+}
+
+// hm://metadata/4/<type>/<hex gid>, answered with a single protobuf part
+pub struct Metadata;
+
+impl Metadata {
+    pub async fn get<T: MetadataItem>(session: &Session, id: &SpotifyId) -> Result<T> {
+        if id.item_type != T::ITEM_TYPE {
+            return Err(MetadataError::WrongItemType {
+                expected: T::ITEM_TYPE,
+                actual: id.item_type,
+            }
+            .into());
+        }
+
+        let uri = format!("hm://metadata/4/{}/{}", T::ITEM_TYPE, id.to_base16());
+        debug!("fetching metadata {uri}");
+        let response = session.mercury().get(uri).await?;
+        if !response.is_success() {
+            return Err(MetadataError::Status(response.status_code).into());
+        }
+
+        let data = response.payload.first().ok_or(MetadataError::Empty)?;
+        let msg = T::Message::parse_from_bytes(data)?;
+        Ok(T::from_message(id.clone(), msg))
+    }
+}
@@ -0,0 +1,460 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::protocol::metadata as proto;
+use crate::spotify_id::{FileId, SpotifyId, SpotifyItemType};
+
+use super::MetadataItem;
+
+pub use crate::protocol::metadata::audio_file::Format as AudioFileFormat;
+pub use crate::protocol::metadata::album::Type as AlbumType;
+pub use crate::protocol::metadata::restriction::Catalogue as RestrictionCatalogue;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: i32,
+    pub month: Option<i32>,
+    pub day: Option<i32>,
+}
+
+impl From<&proto::Date> for Date {
+    fn from(date: &proto::Date) -> Self {
+        Date {
+            year: date.year(),
+            month: date.month.filter(|m| *m > 0),
+            day: date.day.filter(|d| *d > 0),
+        }
+    }
+}
+
+// Countries come as concatenated ISO 3166 codes, e.g. "DEFRSE". The
+// catalogues a restriction is for come either as enum values or as names
+// like the `catalogue` user attribute, e.g. "premium" or "free".
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Restriction {
+    pub countries_allowed: Option<Vec<String>>,
+    pub countries_forbidden: Vec<String>,
+    pub catalogue_types: Vec<RestrictionCatalogue>,
+    pub catalogues: Vec<String>,
+}
+
+impl Restriction {
+    // a restriction that names no catalogue at all is for every one of them
+    pub fn applies_to(&self, catalogue: &str) -> bool {
+        if self.catalogue_types.is_empty() && self.catalogues.is_empty() {
+            return true;
+        }
+        let named = |name: &str| name.eq_ignore_ascii_case(catalogue);
+        self.catalogues.iter().any(|c| named(c))
+            || self.catalogue_types.iter().any(|c| match c {
+                RestrictionCatalogue::CATALOGUE_ALL => true,
+                RestrictionCatalogue::AD => named("free"),
+                RestrictionCatalogue::SUBSCRIPTION => named("premium"),
+                RestrictionCatalogue::SHUFFLE => named("shuffle"),
+                RestrictionCatalogue::COMMERCIAL => named("commercial"),
+            })
+    }
+
+    pub fn allows(&self, country: &str) -> bool {
+        if self.countries_forbidden.iter().any(|c| c.eq_ignore_ascii_case(country)) {
+            return false;
+        }
+        match &self.countries_allowed {
+            Some(allowed) => allowed.iter().any(|c| c.eq_ignore_ascii_case(country)),
+            None => true,
+        }
+    }
+}
+
+impl From<&proto::Restriction> for Restriction {
+    fn from(restriction: &proto::Restriction) -> Self {
+        Restriction {
+            countries_allowed: restriction
+                .countries_allowed
+                .as_deref()
+                .map(split_countries),
+            countries_forbidden: split_countries(restriction.countries_forbidden()),
+            catalogue_types: restriction
+                .catalogue
+                .iter()
+                .filter_map(|c| c.enum_value().ok())
+                .collect(),
+            catalogues: restriction.catalogue_str.clone(),
+        }
+    }
+}
+
+fn split_countries(countries: &str) -> Vec<String> {
+    countries
+        .as_bytes()
+        .chunks(2)
+        .filter_map(|c| std::str::from_utf8(c).ok())
+        .map(str::to_owned)
+        .collect()
+}
+
+// every restriction for the user's catalogue has to allow the country
+fn available_in(restrictions: &[Restriction], country: &str, catalogue: &str) -> bool {
+    restrictions
+        .iter()
+        .filter(|r| r.applies_to(catalogue))
+        .all(|r| r.allows(country))
+}
+
+#[derive(Debug, Clone)]
+pub struct Track {
+    pub id: SpotifyId,
+    pub name: String,
+    pub album: Option<SpotifyId>,
+    pub artists: Vec<SpotifyId>,
+    pub number: i32,
+    pub disc_number: i32,
+    pub duration: Duration,
+    pub popularity: i32,
+    pub explicit: bool,
+    pub files: HashMap<AudioFileFormat, FileId>,
+    pub restrictions: Vec<Restriction>,
+    pub alternatives: Vec<SpotifyId>,
+}
+
+impl Track {
+    // `catalogue` as in `SessionInfo::catalogue`
+    pub fn is_available_in(&self, country: &str, catalogue: &str) -> bool {
+        available_in(&self.restrictions, country, catalogue)
+    }
+}
+
+impl MetadataItem for Track {
+    const ITEM_TYPE: SpotifyItemType = SpotifyItemType::Track;
+    type Message = proto::Track;
+
+    fn from_message(id: SpotifyId, track: proto::Track) -> Self {
+        Track {
+            id,
+            name: track.name().to_owned(),
+            album: track
+                .album
+                .as_ref()
+                .and_then(|album| gid(SpotifyItemType::Album, album.gid())),
+            artists: gids(SpotifyItemType::Artist, track.artist.iter().map(|a| a.gid())),
+            number: track.number(),
+            disc_number: track.disc_number(),
+            duration: millis(track.duration()),
+            popularity: track.popularity(),
+            explicit: track.explicit(),
+            files: audio_files(&track.file),
+            restrictions: track.restriction.iter().map(Restriction::from).collect(),
+            alternatives: gids(SpotifyItemType::Track, track.alternative.iter().map(|t| t.gid())),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Album {
+    pub id: SpotifyId,
+    pub name: String,
+    pub album_type: Option<AlbumType>,
+    pub artists: Vec<SpotifyId>,
+    pub label: String,
+    pub date: Option<Date>,
+    pub popularity: i32,
+    pub genres: Vec<String>,
+    pub covers: Vec<FileId>,
+    // in disc order
+    pub tracks: Vec<SpotifyId>,
+    pub restrictions: Vec<Restriction>,
+}
+
+impl Album {
+    // `catalogue` as in `SessionInfo::catalogue`
+    pub fn is_available_in(&self, country: &str, catalogue: &str) -> bool {
+        available_in(&self.restrictions, country, catalogue)
+    }
+}
+
+impl MetadataItem for Album {
+    const ITEM_TYPE: SpotifyItemType = SpotifyItemType::Album;
+    type Message = proto::Album;
+
+    fn from_message(id: SpotifyId, album: proto::Album) -> Self {
+        let covers = match album.cover_group.as_ref() {
+            Some(group) => file_ids(group.image.iter().map(|i| i.file_id())),
+            None => file_ids(album.cover.iter().map(|i| i.file_id())),
+        };
+
+        Album {
+            id,
+            name: album.name().to_owned(),
+            album_type: album.typ.and_then(|t| t.enum_value().ok()),
+            artists: gids(SpotifyItemType::Artist, album.artist.iter().map(|a| a.gid())),
+            label: album.label().to_owned(),
+            date: album.date.as_ref().map(Date::from),
+            popularity: album.popularity(),
+            genres: album.genre.clone(),
+            covers,
+            tracks: gids(
+                SpotifyItemType::Track,
+                album.disc.iter().flat_map(|disc| disc.track.iter().map(|t| t.gid())),
+            ),
+            restrictions: album.restriction.iter().map(Restriction::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Artist {
+    pub id: SpotifyId,
+    pub name: String,
+    pub popularity: i32,
+    pub genres: Vec<String>,
+    // keyed by country code
+    pub top_tracks: HashMap<String, Vec<SpotifyId>>,
+    pub albums: Vec<SpotifyId>,
+    pub singles: Vec<SpotifyId>,
+    pub compilations: Vec<SpotifyId>,
+    pub portraits: Vec<FileId>,
+    pub restrictions: Vec<Restriction>,
+}
+
+impl MetadataItem for Artist {
+    const ITEM_TYPE: SpotifyItemType = SpotifyItemType::Artist;
+    type Message = proto::Artist;
+
+    fn from_message(id: SpotifyId, artist: proto::Artist) -> Self {
+        let album_ids = |groups: &[proto::AlbumGroup]| {
+            gids(
+                SpotifyItemType::Album,
+                groups.iter().flat_map(|g| g.album.iter().map(|a| a.gid())),
+            )
+        };
+        let portraits = match artist.portrait_group.as_ref() {
+            Some(group) => file_ids(group.image.iter().map(|i| i.file_id())),
+            None => file_ids(artist.portrait.iter().map(|i| i.file_id())),
+        };
+
+        Artist {
+            id,
+            name: artist.name().to_owned(),
+            popularity: artist.popularity(),
+            genres: artist.genre.clone(),
+            top_tracks: artist
+                .top_track
+                .iter()
+                .map(|top| {
+                    let tracks = gids(SpotifyItemType::Track, top.track.iter().map(|t| t.gid()));
+                    (top.country().to_owned(), tracks)
+                })
+                .collect(),
+            albums: album_ids(&artist.album_group),
+            singles: album_ids(&artist.single_group),
+            compilations: album_ids(&artist.compilation_group),
+            portraits,
+            restrictions: artist.restriction.iter().map(Restriction::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Episode {
+    pub id: SpotifyId,
+    pub name: String,
+    pub show: Option<SpotifyId>,
+    pub description: String,
+    pub number: i32,
+    pub duration: Duration,
+    pub publish_date: Option<Date>,
+    pub language: String,
+    pub explicit: bool,
+    pub covers: Vec<FileId>,
+    pub files: HashMap<AudioFileFormat, FileId>,
+    pub restrictions: Vec<Restriction>,
+}
+
+impl Episode {
+    // `catalogue` as in `SessionInfo::catalogue`
+    pub fn is_available_in(&self, country: &str, catalogue: &str) -> bool {
+        available_in(&self.restrictions, country, catalogue)
+    }
+}
+
+impl MetadataItem for Episode {
+    const ITEM_TYPE: SpotifyItemType = SpotifyItemType::Episode;
+    type Message = proto::Episode;
+
+    fn from_message(id: SpotifyId, episode: proto::Episode) -> Self {
+        Episode {
+            id,
+            name: episode.name().to_owned(),
+            show: episode
+                .show
+                .as_ref()
+                .and_then(|show| gid(SpotifyItemType::Show, show.gid())),
+            description: episode.description().to_owned(),
+            number: episode.number(),
+            duration: millis(episode.duration()),
+            publish_date: episode.publish_time.as_ref().map(Date::from),
+            language: episode.language().to_owned(),
+            explicit: episode.explicit(),
+            covers: episode
+                .cover_image
+                .as_ref()
+                .map(|group| file_ids(group.image.iter().map(|i| i.file_id())))
+                .unwrap_or_default(),
+            files: audio_files(&episode.file),
+            restrictions: episode.restriction.iter().map(Restriction::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Show {
+    pub id: SpotifyId,
+    pub name: String,
+    pub publisher: String,
+    pub description: String,
+    pub language: String,
+    pub explicit: bool,
+    pub covers: Vec<FileId>,
+    pub episodes: Vec<SpotifyId>,
+    pub restrictions: Vec<Restriction>,
+}
+
+impl MetadataItem for Show {
+    const ITEM_TYPE: SpotifyItemType = SpotifyItemType::Show;
+    type Message = proto::Show;
+
+    fn from_message(id: SpotifyId, show: proto::Show) -> Self {
+        Show {
+            id,
+            name: show.name().to_owned(),
+            publisher: show.publisher().to_owned(),
+            description: show.description().to_owned(),
+            language: show.language().to_owned(),
+            explicit: show.explicit(),
+            covers: show
+                .cover_image
+                .as_ref()
+                .map(|group| file_ids(group.image.iter().map(|i| i.file_id())))
+                .unwrap_or_default(),
+            episodes: gids(SpotifyItemType::Episode, show.episode.iter().map(|e| e.gid())),
+            restrictions: show.restriction.iter().map(Restriction::from).collect(),
+        }
+    }
+}
+
+fn gid(item_type: SpotifyItemType, raw: &[u8]) -> Option<SpotifyId> {
+    SpotifyId::from_raw(item_type, raw).ok()
+}
+
+fn gids<'a>(item_type: SpotifyItemType, raw: impl Iterator<Item = &'a [u8]>) -> Vec<SpotifyId> {
+    raw.filter_map(|raw| gid(item_type, raw)).collect()
+}
+
+fn file_ids<'a>(raw: impl Iterator<Item = &'a [u8]>) -> Vec<FileId> {
+    raw.filter_map(FileId::from_raw).collect()
+}
+
+fn audio_files(files: &[proto::AudioFile]) -> HashMap<AudioFileFormat, FileId> {
+    files
+        .iter()
+        .filter_map(|file| {
+            let format = file.format?.enum_value().ok()?;
+            Some((format, FileId::from_raw(file.file_id())?))
+        })
+        .collect()
+}
+
+fn millis(ms: i32) -> Duration {
+    Duration::from_millis(ms.max(0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACK_ID: u128 = 238762092608182713602505436543891614649;
+
+    fn restriction(catalogue: &str, allowed: Option<&str>, forbidden: &str) -> proto::Restriction {
+        let mut restriction = proto::Restriction::new();
+        restriction.catalogue_str.push(catalogue.to_owned());
+        restriction.countries_allowed = allowed.map(str::to_owned);
+        restriction.set_countries_forbidden(forbidden.to_owned());
+        restriction
+    }
+
+    fn track() -> proto::Track {
+        let mut track = proto::Track::new();
+        track.set_name("Song".to_owned());
+        track.set_duration(215_000);
+        for (format, byte) in [(AudioFileFormat::OGG_VORBIS_160, 1), (AudioFileFormat::MP3_320, 2)] {
+            let mut file = proto::AudioFile::new();
+            file.set_format(format);
+            file.set_file_id(vec![byte; 20]);
+            track.file.push(file);
+        }
+        // ids of the wrong length are skipped
+        let mut file = proto::AudioFile::new();
+        file.set_format(AudioFileFormat::AAC_24);
+        file.set_file_id(vec![3; 19]);
+        track.file.push(file);
+
+        let mut alternative = proto::Track::new();
+        alternative.set_gid((TRACK_ID + 1).to_be_bytes().to_vec());
+        track.alternative.push(alternative);
+        track
+    }
+
+    #[test]
+    fn track_from_message() {
+        let id = SpotifyId::new(SpotifyItemType::Track, TRACK_ID);
+        let mut message = track();
+        message.restriction.push(restriction("premium", Some("DESE"), ""));
+        let track = Track::from_message(id.clone(), message);
+
+        assert_eq!((track.id, track.name.as_str()), (id, "Song"));
+        assert_eq!(track.duration, Duration::from_millis(215_000));
+        assert_eq!(track.files.len(), 2);
+        assert_eq!(track.files[&AudioFileFormat::OGG_VORBIS_160], FileId([1; 20]));
+        assert_eq!(track.files[&AudioFileFormat::MP3_320], FileId([2; 20]));
+        assert_eq!(track.alternatives, [SpotifyId::new(SpotifyItemType::Track, TRACK_ID + 1)]);
+        assert_eq!(
+            track.restrictions,
+            [Restriction {
+                countries_allowed: Some(vec!["DE".to_owned(), "SE".to_owned()]),
+                countries_forbidden: Vec::new(),
+                catalogue_types: Vec::new(),
+                catalogues: vec!["premium".to_owned()],
+            }]
+        );
+    }
+
+    #[test]
+    fn availability_per_catalogue() {
+        let mut message = track();
+        message.restriction.push(restriction("free", None, "SE"));
+        message.restriction.push(restriction("premium", Some("SEDE"), ""));
+        let track = Track::from_message(SpotifyId::new(SpotifyItemType::Track, TRACK_ID), message);
+
+        assert!(track.is_available_in("SE", "premium"));
+        assert!(!track.is_available_in("SE", "free"));
+        assert!(track.is_available_in("DE", "free"));
+        assert!(!track.is_available_in("FR", "premium"));
+    }
+
+    #[test]
+    fn restriction_catalogue_types() {
+        let mut message = proto::Restriction::new();
+        message.catalogue.push(RestrictionCatalogue::SUBSCRIPTION.into());
+        message.set_countries_forbidden("US".to_owned());
+        let restriction = Restriction::from(&message);
+
+        assert_eq!(restriction.catalogue_types, [RestrictionCatalogue::SUBSCRIPTION]);
+        assert!(restriction.applies_to("premium"));
+        assert!(!restriction.applies_to("free"));
+        let restrictions = [restriction];
+        assert!(!available_in(&restrictions, "US", "premium"));
+        assert!(available_in(&restrictions, "US", "free"));
+        // no catalogue given, applies everywhere
+        assert!(Restriction::default().applies_to("free"));
+    }
+}
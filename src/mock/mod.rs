@@ -0,0 +1,319 @@
+use std::io;
+use std::sync::Arc;
+
+use futures_util::{SinkExt, StreamExt};
+use log::{debug, warn};
+use protobuf::Message;
+use rand::RngCore;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::{Pkcs1v15Sign, RsaPrivateKey, RsaPublicKey};
+use sha1::{Digest, Sha1};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio_util::codec::{Decoder, Framed};
+
+use crate::codec::ApCodec;
+use crate::consts::PacketType;
+use crate::dh::DhLocalKeys;
+use crate::error::{Error, Result};
+use crate::handshake::{HandshakeConfig, HandshakeError, compute_keys};
+use crate::hashcash;
+use crate::packet::Packet;
+
+use crate::protocol::authentication::{
+    APWelcome, AccountType, AuthenticationType, ClientResponseEncrypted,
+};
+use crate::protocol::keyexchange::{
+    APLoginFailed, APResponseMessage, ClientHello, ClientResponsePlaintext, Cryptosuite, ErrorCode,
+};
+
+// never use outside of tests, the private half is public knowledge
+const TEST_KEY: &str = include_str!("test_key.pem");
+
+pub fn test_private_key() -> RsaPrivateKey {
+    RsaPrivateKey::from_pkcs1_pem(TEST_KEY).expect("valid test key")
+}
+
+pub fn test_server_key() -> RsaPublicKey {
+    test_private_key().to_public_key()
+}
+
+// client configuration that trusts the mock instead of the real access points
+pub fn test_handshake_config() -> HandshakeConfig {
+    HandshakeConfig::default().server_keys(vec![test_server_key()])
+}
+
+#[derive(Debug, Clone)]
+pub enum MockLogin {
+    Welcome(APWelcome),
+    Failure(APLoginFailed),
+}
+
+impl MockLogin {
+    pub fn welcome(username: &str) -> MockLogin {
+        let mut welcome = APWelcome::new();
+        welcome.set_canonical_username(username.to_owned());
+        welcome.set_account_type_logged_in(AccountType::Spotify);
+        welcome.set_credentials_type_logged_in(AccountType::Spotify);
+        welcome.set_reusable_auth_credentials_type(
+            AuthenticationType::AUTHENTICATION_STORED_SPOTIFY_CREDENTIALS,
+        );
+        welcome.set_reusable_auth_credentials(b"mock-credentials".to_vec());
+        MockLogin::Welcome(welcome)
+    }
+
+    pub fn failure(error_code: ErrorCode) -> MockLogin {
+        let mut failed = APLoginFailed::new();
+        failed.set_error_code(error_code);
+        MockLogin::Failure(failed)
+    }
+}
+
+impl Default for MockLogin {
+    fn default() -> Self {
+        MockLogin::welcome("mock")
+    }
+}
+
+// answered instead of the challenge
+#[derive(Debug, Clone)]
+pub enum MockRejection {
+    Upgrade { http_suffix: String },
+    LoginFailed(ErrorCode),
+}
+
+// Server side of the AP handshake and login, for exercising the client
+// without network access.
+pub struct MockAccessPoint {
+    key: RsaPrivateKey,
+    login: MockLogin,
+    pow_length: Option<i32>,
+    rejection: Option<MockRejection>,
+    cryptosuite: Cryptosuite,
+}
+
+impl Default for MockAccessPoint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockAccessPoint {
+    pub fn new() -> MockAccessPoint {
+        MockAccessPoint {
+            key: test_private_key(),
+            login: MockLogin::default(),
+            pow_length: None,
+            rejection: None,
+            cryptosuite: Cryptosuite::CRYPTO_SUITE_SHANNON,
+        }
+    }
+
+    pub fn with_key(mut self, key: RsaPrivateKey) -> MockAccessPoint {
+        self.key = key;
+        self
+    }
+
+    pub fn with_login(mut self, login: MockLogin) -> MockAccessPoint {
+        self.login = login;
+        self
+    }
+
+    // demand a hashcash proof-of-work with `length` zero bits
+    pub fn with_pow(mut self, length: i32) -> MockAccessPoint {
+        self.pow_length = Some(length);
+        self
+    }
+
+    pub fn with_rejection(mut self, rejection: MockRejection) -> MockAccessPoint {
+        self.rejection = Some(rejection);
+        self
+    }
+
+    // the suite picked in the challenge, the client must have offered it
+    pub fn with_cryptosuite(mut self, cryptosuite: Cryptosuite) -> MockAccessPoint {
+        self.cryptosuite = cryptosuite;
+        self
+    }
+
+    pub fn public_key(&self) -> RsaPublicKey {
+        self.key.to_public_key()
+    }
+
+    // Runs the handshake and answers the login packet. The returned transport
+    // can be used to push further packets to the client.
+    pub async fn serve<T: AsyncRead + AsyncWrite + Unpin>(&self, conn: T) -> Result<Framed<T, ApCodec>> {
+        let mut transport = self.handshake(conn).await?;
+
+        let packet = transport.next().await.ok_or(Error::SessionClosed)??;
+        if packet.kind() != PacketType::Login {
+            return Err(Error::UnexpectedPacket(packet.cmd()));
+        }
+        let login = ClientResponseEncrypted::parse_from_bytes(&packet.payload)?;
+        debug!(
+            "mock login from {:?} ({:?})",
+            login.login_credentials.username(),
+            login.login_credentials.typ()
+        );
+
+        let reply = match &self.login {
+            MockLogin::Welcome(welcome) => Packet::new(PacketType::APWelcome, welcome.write_to_bytes()?),
+            MockLogin::Failure(failed) => Packet::new(PacketType::AuthFailure, failed.write_to_bytes()?),
+        };
+        transport.send(reply).await?;
+
+        Ok(transport)
+    }
+
+    pub async fn handshake<T: AsyncRead + AsyncWrite + Unpin>(&self, mut conn: T) -> Result<Framed<T, ApCodec>> {
+        let mut accumulator = Vec::new();
+
+        // ClientHello is the only packet with a two byte version prefix
+        let mut header = [0u8; 6];
+        conn.read_exact(&mut header).await?;
+        accumulator.extend_from_slice(&header);
+        let size = u32::from_be_bytes([header[2], header[3], header[4], header[5]]) as usize;
+        let data = read_exact(&mut conn, size.saturating_sub(6), &mut accumulator).await?;
+        let hello = ClientHello::parse_from_bytes(&data)?;
+        let remote_key = hello.login_crypto_hello.diffie_hellman.gc().to_owned();
+
+        if let Some(rejection) = &self.rejection {
+            let packet = self.rejection(rejection)?;
+            write_packet(&mut conn, &packet, &mut Vec::new()).await?;
+            return Err(io::Error::new(io::ErrorKind::ConnectionAborted, "handshake rejected").into());
+        }
+
+        let local_keys = DhLocalKeys::random(&mut rand::rng());
+        let packet = self.challenge(local_keys.public_key())?;
+        let pow_challenge = packet.challenge.pow_challenge.hash_cash.clone().into_option();
+        write_packet(&mut conn, &packet, &mut accumulator).await?;
+
+        let shared_secret = local_keys.shared_secret(&remote_key);
+        let (challenge, send_key, recv_key) = compute_keys(&shared_secret, &accumulator)?;
+
+        let mut header = [0u8; 4];
+        conn.read_exact(&mut header).await?;
+        let size = u32::from_be_bytes(header) as usize;
+        let data = read_exact(&mut conn, size.saturating_sub(4), &mut Vec::new()).await?;
+        let response = ClientResponsePlaintext::parse_from_bytes(&data)?;
+        if response.login_crypto_response.diffie_hellman.hmac() != challenge.as_slice() {
+            return Err(HandshakeError::VerificationFailed.into());
+        }
+        if let Some(pow) = pow_challenge {
+            let suffix = response.pow_response.hash_cash.hash_suffix();
+            if !hashcash::verify(pow.prefix(), suffix, pow.length() as u32) {
+                return Err(HandshakeError::PowFailed.into());
+            }
+        }
+
+        // the client's send key is our receive key and vice versa
+        Ok(ApCodec::with_cryptosuite(self.cryptosuite, &recv_key, &send_key)?.framed(conn))
+    }
+
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let hash = Sha1::digest(data);
+        let signature = self
+            .key
+            .sign(Pkcs1v15Sign::new::<Sha1>(), &hash)
+            .map_err(|_| HandshakeError::VerificationFailed)?;
+        Ok(signature)
+    }
+
+    fn rejection(&self, rejection: &MockRejection) -> Result<APResponseMessage> {
+        let mut packet = APResponseMessage::new();
+        match rejection {
+            MockRejection::Upgrade { http_suffix } => {
+                let signed_part = b"mock-upgrade".to_vec();
+                let upgrade = packet.upgrade.mut_or_insert_default();
+                upgrade.set_signature(self.sign(&signed_part)?);
+                upgrade.set_upgrade_signed_part(signed_part);
+                upgrade.set_http_suffix(http_suffix.clone());
+            }
+            MockRejection::LoginFailed(error_code) => {
+                let failed = packet.login_failed.mut_or_insert_default();
+                failed.set_error_code(*error_code);
+                failed.set_retry_delay(30);
+            }
+        }
+        Ok(packet)
+    }
+
+    fn challenge(&self, public_key: Vec<u8>) -> Result<APResponseMessage> {
+        let signature = self.sign(&public_key)?;
+
+        let mut server_nonce = vec![0; 0x10];
+        rand::rng().fill_bytes(&mut server_nonce);
+
+        let mut packet = APResponseMessage::new();
+        let challenge = packet.challenge.mut_or_insert_default();
+        let diffie_hellman = challenge
+            .login_crypto_challenge
+            .mut_or_insert_default()
+            .diffie_hellman
+            .mut_or_insert_default();
+        diffie_hellman.set_gs(public_key);
+        diffie_hellman.set_server_signature_key(0);
+        diffie_hellman.set_gs_signature(signature);
+        challenge.fingerprint_challenge.mut_or_insert_default();
+        let pow_challenge = challenge.pow_challenge.mut_or_insert_default();
+        if let Some(length) = self.pow_length {
+            let mut prefix = vec![0; 0x10];
+            rand::rng().fill_bytes(&mut prefix);
+
+            let hash_cash = pow_challenge.hash_cash.mut_or_insert_default();
+            hash_cash.set_prefix(prefix);
+            hash_cash.set_length(length);
+            hash_cash.set_target(rand::rng().next_u32() as i32);
+        }
+        let crypto_challenge = challenge.crypto_challenge.mut_or_insert_default();
+        match self.cryptosuite {
+            Cryptosuite::CRYPTO_SUITE_SHANNON => {
+                crypto_challenge.shannon.mut_or_insert_default();
+            }
+            Cryptosuite::CRYPTO_SUITE_RC4_SHA1_HMAC => {
+                crypto_challenge.rc4_sha1_hmac.mut_or_insert_default();
+            }
+        }
+        challenge.set_server_nonce(server_nonce);
+
+        Ok(packet)
+    }
+
+    pub async fn listen(self, listener: TcpListener) -> Result<()> {
+        let server = Arc::new(self);
+        loop {
+            let (conn, addr) = listener.accept().await?;
+            debug!("mock connection from {addr}");
+
+            let server = server.clone();
+            tokio::spawn(async move {
+                match server.serve(conn).await {
+                    // keep the connection open until the client goes away
+                    Ok(mut transport) => while let Some(Ok(_)) = transport.next().await {},
+                    Err(e) => warn!("mock connection from {addr} failed: {e}"),
+                }
+            });
+        }
+    }
+}
+
+async fn write_packet<T, M>(conn: &mut T, packet: &M, acc: &mut Vec<u8>) -> Result<()>
+where
+    T: AsyncWrite + Unpin,
+    M: Message,
+{
+    let size = 4 + packet.compute_size() as usize;
+    let mut buf = Vec::with_capacity(size);
+    buf.extend_from_slice(&(size as u32).to_be_bytes());
+    packet.write_to_vec(&mut buf)?;
+    conn.write_all(&buf).await?;
+    acc.extend_from_slice(&buf);
+    Ok(())
+}
+
+async fn read_exact<T: AsyncRead + Unpin>(conn: &mut T, size: usize, acc: &mut Vec<u8>) -> Result<Vec<u8>> {
+    let mut data = vec![0u8; size];
+    conn.read_exact(&mut data).await?;
+    acc.extend_from_slice(&data);
+    Ok(data)
+}
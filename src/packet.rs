@@ -0,0 +1,150 @@
+use bytes::{Buf, Bytes};
+
+use crate::consts::PacketType;
+use crate::error::{Error, Result};
+
+// A decrypted AP packet. `cmd` keeps the wire byte so packets this crate
+// does not know about can still be inspected or forwarded; `kind` is always
+// derived from it, which is why neither can be changed on its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    kind: PacketType,
+    pub payload: Bytes,
+    cmd: u8,
+}
+
+impl Packet {
+    pub fn new(kind: PacketType, payload: impl Into<Bytes>) -> Packet {
+        Packet {
+            kind,
+            payload: payload.into(),
+            cmd: kind as u8,
+        }
+    }
+
+    pub fn from_raw(cmd: u8, payload: impl Into<Bytes>) -> Packet {
+        Packet {
+            kind: PacketType::from(cmd),
+            payload: payload.into(),
+            cmd,
+        }
+    }
+
+    pub fn kind(&self) -> PacketType {
+        self.kind
+    }
+
+    pub fn cmd(&self) -> u8 {
+        self.cmd
+    }
+
+    // parses the simple non-protobuf payloads, None for every other type
+    pub fn decode_payload(&self) -> Result<Option<PacketPayload>> {
+        let mut data = self.payload.clone();
+        let payload = match self.kind {
+            PacketType::CountryCode => {
+                let len = data.len();
+                PacketPayload::CountryCode(read_string(&mut data, self.cmd, len)?)
+            }
+            PacketType::Ping => {
+                if data.len() < 4 {
+                    return Err(Error::MalformedPacket(self.cmd));
+                }
+                PacketPayload::Ping(data.get_u32())
+            }
+            PacketType::LicenseVersion => {
+                if data.len() < 2 {
+                    return Err(Error::MalformedPacket(self.cmd));
+                }
+                let id = data.get_u16();
+                // a zero id carries no license string
+                let name = if id != 0 && data.has_remaining() {
+                    let len = data.get_u8() as usize;
+                    Some(read_string(&mut data, self.cmd, len)?)
+                } else {
+                    None
+                };
+                PacketPayload::LicenseVersion { id, name }
+            }
+            PacketType::PreferredLocale => {
+                // 2 unknown bytes, key_len u8, value_len u16, key, value
+                if data.len() < 5 {
+                    return Err(Error::MalformedPacket(self.cmd));
+                }
+                data.advance(2);
+                let key_len = data.get_u8() as usize;
+                let value_len = data.get_u16() as usize;
+                let key = read_string(&mut data, self.cmd, key_len)?;
+                let value = read_string(&mut data, self.cmd, value_len)?;
+                PacketPayload::PreferredLocale { key, value }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(payload))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketPayload {
+    CountryCode(String),
+    // server time in seconds since the epoch
+    Ping(u32),
+    LicenseVersion { id: u16, name: Option<String> },
+    PreferredLocale { key: String, value: String },
+}
+
+fn read_string(data: &mut Bytes, cmd: u8, len: usize) -> Result<String> {
+    if data.len() < len {
+        return Err(Error::MalformedPacket(cmd));
+    }
+    let bytes = data.split_to(len);
+    String::from_utf8(bytes.to_vec()).map_err(|_| Error::MalformedPacket(cmd))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(kind: PacketType, payload: &[u8]) -> Result<Option<PacketPayload>> {
+        Packet::new(kind, payload.to_vec()).decode_payload()
+    }
+
+    #[test]
+    fn decode_payloads() {
+        let country = decode(PacketType::CountryCode, b"SE").unwrap();
+        assert_eq!(country, Some(PacketPayload::CountryCode("SE".to_owned())));
+
+        let ping = decode(PacketType::Ping, &[0x65, 0x00, 0x00, 0x2a]).unwrap();
+        assert_eq!(ping, Some(PacketPayload::Ping(0x6500002a)));
+
+        let license = decode(PacketType::LicenseVersion, b"\x00\x05\x07premium").unwrap();
+        let name = Some("premium".to_owned());
+        assert_eq!(license, Some(PacketPayload::LicenseVersion { id: 5, name }));
+        let license = decode(PacketType::LicenseVersion, &[0, 0]).unwrap();
+        assert_eq!(license, Some(PacketPayload::LicenseVersion { id: 0, name: None }));
+
+        let locale = decode(PacketType::PreferredLocale, b"\x00\x00\x10\x00\x02preferred-localeen").unwrap();
+        let (key, value) = ("preferred-locale".to_owned(), "en".to_owned());
+        assert_eq!(locale, Some(PacketPayload::PreferredLocale { key, value }));
+
+        assert_eq!(decode(PacketType::Pong, &[0, 0, 0, 0]).unwrap(), None);
+    }
+
+    #[test]
+    fn malformed_payloads() {
+        let truncated = [
+            (PacketType::Ping, &b"\x00\x00\x00"[..]),
+            (PacketType::LicenseVersion, b"\x00"),
+            (PacketType::LicenseVersion, b"\x00\x05\x07prem"),
+            (PacketType::PreferredLocale, b"\x00\x00\x10\x00"),
+            (PacketType::PreferredLocale, b"\x00\x00\x10\x00\x02preferred-locale"),
+            (PacketType::CountryCode, b"\xff"),
+        ];
+        for (kind, payload) in truncated {
+            assert!(
+                matches!(decode(kind, payload), Err(Error::MalformedPacket(cmd)) if cmd == kind as u8),
+                "{kind:?} {payload:02x?}"
+            );
+        }
+    }
+}
@@ -3,3 +3,4 @@
 pub mod authentication;
 pub mod keyexchange;
 pub mod mercury;
+pub mod metadata;
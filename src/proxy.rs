@@ -0,0 +1,238 @@
+use std::fmt;
+use std::str::FromStr;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use log::debug;
+use percent_encoding::percent_decode_str;
+use reqwest::Url;
+use thiserror::Error;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+use crate::error::Result;
+
+const SOCKS_VERSION: u8 = 5;
+const SOCKS_NO_AUTH: u8 = 0x00;
+const SOCKS_USER_PASS: u8 = 0x02;
+const SOCKS_NO_ACCEPTABLE: u8 = 0xff;
+const SOCKS_CONNECT: u8 = 0x01;
+const SOCKS_ATYP_IPV4: u8 = 0x01;
+const SOCKS_ATYP_DOMAIN: u8 = 0x03;
+const SOCKS_ATYP_IPV6: u8 = 0x04;
+
+// the status line and headers of a CONNECT reply, anything longer is bogus
+const MAX_CONNECT_REPLY: usize = 8 * 1024;
+
+#[derive(Debug, Error)]
+pub enum ProxyError {
+    #[error("invalid proxy url {0:?}")]
+    InvalidUrl(String),
+    #[error("unsupported proxy scheme {0:?}")]
+    UnsupportedScheme(String),
+    #[error("proxy refused the tunnel: {0}")]
+    ConnectRejected(String),
+    #[error("malformed proxy reply")]
+    Malformed,
+    #[error("proxy accepts none of the offered authentication methods")]
+    NoAcceptableAuth,
+    #[error("proxy authentication failed")]
+    AuthFailed,
+    #[error("socks5 connect failed with reply {0:#04x}")]
+    SocksReply(u8),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ProxyScheme {
+    Http,
+    Socks5,
+}
+
+// An `http://` or `socks5://` proxy, optionally with `user:password@`.
+// Used for apresolve and tunnels the AP connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proxy {
+    pub scheme: ProxyScheme,
+    pub host: String,
+    pub port: u16,
+    pub credentials: Option<(String, String)>,
+}
+
+impl FromStr for Proxy {
+    type Err = ProxyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let url = Url::parse(s).map_err(|_| ProxyError::InvalidUrl(s.to_owned()))?;
+        let scheme = match url.scheme() {
+            "http" => ProxyScheme::Http,
+            // name resolution always happens on the proxy
+            "socks5" | "socks5h" => ProxyScheme::Socks5,
+            other => return Err(ProxyError::UnsupportedScheme(other.to_owned())),
+        };
+        let host = url
+            .host_str()
+            .ok_or_else(|| ProxyError::InvalidUrl(s.to_owned()))?
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_owned();
+        let port = url.port().unwrap_or(match scheme {
+            ProxyScheme::Http => 8080,
+            ProxyScheme::Socks5 => 1080,
+        });
+        // the url keeps userinfo percent-encoded, e.g. an `@` in a password
+        let decode = |part: &str| {
+            percent_decode_str(part)
+                .decode_utf8()
+                .map(|part| part.into_owned())
+                .map_err(|_| ProxyError::InvalidUrl(s.to_owned()))
+        };
+        let credentials = match url.username() {
+            "" => None,
+            username => Some((decode(username)?, decode(url.password().unwrap_or_default())?)),
+        };
+
+        Ok(Proxy {
+            scheme,
+            host,
+            port,
+            credentials,
+        })
+    }
+}
+
+impl fmt::Display for Proxy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scheme = match self.scheme {
+            ProxyScheme::Http => "http",
+            ProxyScheme::Socks5 => "socks5h",
+        };
+        // credentials are left out on purpose, this ends up in logs
+        if self.host.contains(':') {
+            write!(f, "{scheme}://[{}]:{}", self.host, self.port)
+        } else {
+            write!(f, "{scheme}://{}:{}", self.host, self.port)
+        }
+    }
+}
+
+impl Proxy {
+    pub fn reqwest_proxy(&self) -> reqwest::Result<reqwest::Proxy> {
+        let proxy = reqwest::Proxy::all(self.to_string())?;
+        Ok(match &self.credentials {
+            Some((username, password)) => proxy.basic_auth(username, password),
+            None => proxy,
+        })
+    }
+
+    // opens a tunnel to `host:port`, the returned stream talks to the target
+    pub async fn connect(&self, host: &str, port: u16) -> Result<TcpStream> {
+        debug!("connecting to {host}:{port} through {self}");
+        let mut stream = TcpStream::connect((self.host.as_str(), self.port)).await?;
+        match self.scheme {
+            ProxyScheme::Http => self.http_connect(&mut stream, host, port).await?,
+            ProxyScheme::Socks5 => self.socks5_connect(&mut stream, host, port).await?,
+        }
+        Ok(stream)
+    }
+
+    async fn http_connect(&self, stream: &mut TcpStream, host: &str, port: u16) -> Result<()> {
+        let authority = if host.contains(':') {
+            format!("[{host}]:{port}")
+        } else {
+            format!("{host}:{port}")
+        };
+        let mut request = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
+        if let Some((username, password)) = &self.credentials {
+            let token = STANDARD.encode(format!("{username}:{password}"));
+            request.push_str(&format!("Proxy-Authorization: Basic {token}\r\n"));
+        }
+        request.push_str("\r\n");
+        stream.write_all(request.as_bytes()).await?;
+
+        // read byte by byte so nothing past the headers is consumed
+        let mut reply = Vec::new();
+        while !reply.ends_with(b"\r\n\r\n") {
+            if reply.len() >= MAX_CONNECT_REPLY {
+                return Err(ProxyError::Malformed.into());
+            }
+            reply.push(stream.read_u8().await?);
+        }
+
+        let status_line = reply
+            .split(|b| *b == b'\n')
+            .next()
+            .map(|line| String::from_utf8_lossy(line).trim().to_owned())
+            .unwrap_or_default();
+        let status = status_line.split_whitespace().nth(1).ok_or(ProxyError::Malformed)?;
+        if !status.starts_with('2') {
+            return Err(ProxyError::ConnectRejected(status_line).into());
+        }
+        Ok(())
+    }
+
+    async fn socks5_connect(&self, stream: &mut TcpStream, host: &str, port: u16) -> Result<()> {
+        let method = match self.credentials {
+            Some(_) => SOCKS_USER_PASS,
+            None => SOCKS_NO_AUTH,
+        };
+        stream.write_all(&[SOCKS_VERSION, 1, method]).await?;
+
+        let mut reply = [0u8; 2];
+        stream.read_exact(&mut reply).await?;
+        if reply[0] != SOCKS_VERSION {
+            return Err(ProxyError::Malformed.into());
+        }
+        match reply[1] {
+            SOCKS_NO_AUTH => {}
+            SOCKS_USER_PASS => self.socks5_auth(stream).await?,
+            SOCKS_NO_ACCEPTABLE => return Err(ProxyError::NoAcceptableAuth.into()),
+            _ => return Err(ProxyError::Malformed.into()),
+        }
+
+        let host = host.as_bytes();
+        let host_len = u8::try_from(host.len()).map_err(|_| ProxyError::Malformed)?;
+        let mut request = vec![SOCKS_VERSION, SOCKS_CONNECT, 0, SOCKS_ATYP_DOMAIN, host_len];
+        request.extend_from_slice(host);
+        request.extend_from_slice(&port.to_be_bytes());
+        stream.write_all(&request).await?;
+
+        // VER REP RSV ATYP, then the bound address and port which we don't need
+        let mut reply = [0u8; 4];
+        stream.read_exact(&mut reply).await?;
+        if reply[0] != SOCKS_VERSION {
+            return Err(ProxyError::Malformed.into());
+        }
+        if reply[1] != 0 {
+            return Err(ProxyError::SocksReply(reply[1]).into());
+        }
+        let addr_len = match reply[3] {
+            SOCKS_ATYP_IPV4 => 4,
+            SOCKS_ATYP_IPV6 => 16,
+            SOCKS_ATYP_DOMAIN => stream.read_u8().await? as usize,
+            _ => return Err(ProxyError::Malformed.into()),
+        };
+        let mut bound = vec![0u8; addr_len + 2];
+        stream.read_exact(&mut bound).await?;
+        Ok(())
+    }
+
+    // RFC 1929 username/password subnegotiation
+    async fn socks5_auth(&self, stream: &mut TcpStream) -> Result<()> {
+        let (username, password) = self.credentials.as_ref().ok_or(ProxyError::Malformed)?;
+        let username_len = u8::try_from(username.len()).map_err(|_| ProxyError::AuthFailed)?;
+        let password_len = u8::try_from(password.len()).map_err(|_| ProxyError::AuthFailed)?;
+
+        let mut request = vec![1, username_len];
+        request.extend_from_slice(username.as_bytes());
+        request.push(password_len);
+        request.extend_from_slice(password.as_bytes());
+        stream.write_all(&request).await?;
+
+        let mut reply = [0u8; 2];
+        stream.read_exact(&mut reply).await?;
+        if reply[1] != 0 {
+            return Err(ProxyError::AuthFailed.into());
+        }
+        Ok(())
+    }
+}
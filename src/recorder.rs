@@ -0,0 +1,330 @@
+use std::fmt::Write as _;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use bytes::{Buf, Bytes};
+use futures_util::{Sink, Stream};
+use log::warn;
+use protobuf::{Message, MessageFull, text_format};
+
+use crate::codec::MAX_PAYLOAD_SIZE;
+use crate::consts::PacketType;
+use crate::error::{Error, Result};
+use crate::packet::Packet;
+
+use crate::protocol::authentication::{APWelcome, ClientResponseEncrypted};
+use crate::protocol::keyexchange::APLoginFailed;
+use crate::protocol::mercury::Header;
+
+// File layout: MAGIC, then one record per frame
+// +--------------+-----------+--------+-------------+---------+
+// | TIMESTAMP    | DIRECTION | CMD    | PAYLOAD_LEN | PAYLOAD |
+// +--------------+-----------+--------+-------------+---------+
+// | u64 BE, usec | u8        | u8     | u32 BE      | N bytes |
+// +--------------+-----------+--------+-------------+---------+
+const MAGIC: &[u8; 8] = b"FYSREC\x00\x01";
+const RECORD_HEADER_SIZE: usize = 8 + 1 + 1 + 4;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    Inbound,
+    Outbound,
+}
+
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub timestamp: SystemTime,
+    pub direction: Direction,
+    pub packet: Packet,
+}
+
+// recordings hold every Mercury body, keep them as private as the credentials cache
+#[cfg(unix)]
+fn open_private(path: &Path, options: &mut OpenOptions) -> io::Result<File> {
+    use std::fs::Permissions;
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    let file = options.mode(0o600).open(path)?;
+    // the mode only applies to new files
+    file.set_permissions(Permissions::from_mode(0o600))?;
+    Ok(file)
+}
+
+#[cfg(not(unix))]
+fn open_private(path: &Path, options: &mut OpenOptions) -> io::Result<File> {
+    options.open(path)
+}
+
+pub struct Recorder {
+    out: BufWriter<File>,
+}
+
+impl Recorder {
+    pub fn create(path: impl AsRef<Path>) -> Result<Recorder> {
+        let file = open_private(path.as_ref(), OpenOptions::new().write(true).create(true).truncate(true))
+            .map_err(Error::Recorder)?;
+        let mut out = BufWriter::new(file);
+        out.write_all(MAGIC).map_err(Error::Recorder)?;
+        Ok(Recorder { out })
+    }
+
+    // continues an existing recording, or starts one if there is none yet
+    pub fn append(path: impl AsRef<Path>) -> Result<Recorder> {
+        let file = open_private(path.as_ref(), OpenOptions::new().append(true).create(true))
+            .map_err(Error::Recorder)?;
+        let empty = file.metadata().map_err(Error::Recorder)?.len() == 0;
+        let mut out = BufWriter::new(file);
+        if empty {
+            out.write_all(MAGIC).map_err(Error::Recorder)?;
+        }
+        Ok(Recorder { out })
+    }
+
+    pub fn record(&mut self, direction: Direction, packet: &Packet) -> io::Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_micros() as u64;
+        let payload = match (direction, packet.kind()) {
+            (Direction::Outbound, PacketType::Login) => redact_login(&packet.payload),
+            (Direction::Inbound, PacketType::APWelcome) => redact_welcome(&packet.payload),
+            _ => packet.payload.clone(),
+        };
+
+        self.out.write_all(&timestamp.to_be_bytes())?;
+        self.out.write_all(&[direction as u8, packet.cmd()])?;
+        self.out.write_all(&(payload.len() as u32).to_be_bytes())?;
+        self.out.write_all(&payload)
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+// passwords and reusable credentials must never end up on disk
+fn redact_login(payload: &Bytes) -> Bytes {
+    let Ok(mut login) = ClientResponseEncrypted::parse_from_bytes(payload) else {
+        return Bytes::new();
+    };
+    if let Some(credentials) = login.login_credentials.as_mut() {
+        credentials.clear_auth_data();
+    }
+    login.write_to_bytes().map(Bytes::from).unwrap_or_default()
+}
+
+fn redact_welcome(payload: &Bytes) -> Bytes {
+    let Ok(mut welcome) = APWelcome::parse_from_bytes(payload) else {
+        return Bytes::new();
+    };
+    // the field is required, clearing it would fail serialization
+    welcome.set_reusable_auth_credentials(Vec::new());
+    welcome.write_to_bytes().map(Bytes::from).unwrap_or_default()
+}
+
+// Stream + Sink wrapper that records every frame passing through. Without a
+// recorder it is a plain pass-through; write errors stop the recording but
+// never the connection.
+pub struct Recorded<S> {
+    inner: S,
+    recorder: Option<Recorder>,
+}
+
+impl<S> Recorded<S> {
+    pub fn new(inner: S, recorder: Option<Recorder>) -> Recorded<S> {
+        Recorded { inner, recorder }
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn record(&mut self, direction: Direction, packet: &Packet) {
+        if let Some(recorder) = &mut self.recorder
+            && let Err(e) = recorder.record(direction, packet)
+        {
+            warn!("traffic recording stopped: {e}");
+            self.recorder = None;
+        }
+    }
+}
+
+impl<S> Stream for Recorded<S>
+where
+    S: Stream<Item = Result<Packet>> + Unpin,
+{
+    type Item = Result<Packet>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = Pin::new(&mut self.inner).poll_next(cx);
+        if let Poll::Ready(Some(Ok(packet))) = &poll {
+            self.record(Direction::Inbound, packet);
+        }
+        poll
+    }
+}
+
+impl<S> Sink<Packet> for Recorded<S>
+where
+    S: Sink<Packet, Error = Error> + Unpin,
+{
+    type Error = Error;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        Pin::new(&mut self.inner).poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, packet: Packet) -> Result<()> {
+        self.record(Direction::Outbound, &packet);
+        Pin::new(&mut self.inner).start_send(packet)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        if let Some(recorder) = &mut self.recorder
+            && let Err(e) = recorder.flush()
+        {
+            warn!("traffic recording stopped: {e}");
+            self.recorder = None;
+        }
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        if let Some(recorder) = &mut self.recorder {
+            let _ = recorder.flush();
+        }
+        Pin::new(&mut self.inner).poll_close(cx)
+    }
+}
+
+pub struct RecordReader<R> {
+    input: R,
+}
+
+impl RecordReader<BufReader<File>> {
+    pub fn open(path: impl AsRef<Path>) -> Result<RecordReader<BufReader<File>>> {
+        let file = File::open(path).map_err(Error::Recorder)?;
+        RecordReader::new(BufReader::new(file))
+    }
+}
+
+impl<R: Read> RecordReader<R> {
+    pub fn new(mut input: R) -> Result<RecordReader<R>> {
+        let mut magic = [0u8; MAGIC.len()];
+        input.read_exact(&mut magic).map_err(Error::Recorder)?;
+        if &magic != MAGIC {
+            return Err(Error::Recorder(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "not a fyspoti recording",
+            )));
+        }
+        Ok(RecordReader { input })
+    }
+
+    fn read_record(&mut self) -> io::Result<Option<Record>> {
+        let mut header = [0u8; RECORD_HEADER_SIZE];
+        match self.input.read_exact(&mut header) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        }
+        let mut header = &header[..];
+        let timestamp = UNIX_EPOCH + Duration::from_micros(header.get_u64());
+        let direction = match header.get_u8() {
+            0 => Direction::Inbound,
+            1 => Direction::Outbound,
+            d => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid direction {d}"),
+                ));
+            }
+        };
+        let cmd = header.get_u8();
+        let len = header.get_u32() as usize;
+        // nothing larger was ever on the wire, a corrupt length must not size the buffer
+        if len > MAX_PAYLOAD_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("record of {len} bytes exceeds the frame limit"),
+            ));
+        }
+
+        let mut payload = vec![0u8; len];
+        self.input.read_exact(&mut payload)?;
+
+        Ok(Some(Record {
+            timestamp,
+            direction,
+            packet: Packet::from_raw(cmd, payload),
+        }))
+    }
+}
+
+impl<R: Read> Iterator for RecordReader<R> {
+    type Item = Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().map_err(Error::Recorder).transpose()
+    }
+}
+
+// human readable form of the payloads this crate knows how to decode
+pub fn describe(packet: &Packet) -> Option<String> {
+    match packet.kind() {
+        PacketType::Login => describe_message::<ClientResponseEncrypted>(&packet.payload),
+        PacketType::APWelcome => describe_message::<APWelcome>(&packet.payload),
+        PacketType::AuthFailure => describe_message::<APLoginFailed>(&packet.payload),
+        PacketType::MercuryReq
+        | PacketType::MercurySub
+        | PacketType::MercuryUnsub
+        | PacketType::MercuryEvent => describe_mercury(packet.payload.clone()),
+        _ => match packet.decode_payload() {
+            Ok(Some(payload)) => Some(format!("{payload:?}")),
+            _ => None,
+        },
+    }
+}
+
+fn describe_message<M: MessageFull>(payload: &[u8]) -> Option<String> {
+    let message = M::parse_from_bytes(payload).ok()?;
+    Some(text_format::print_to_string_pretty(&message))
+}
+
+fn describe_mercury(mut data: Bytes) -> Option<String> {
+    let mut out = String::new();
+    if data.len() < 2 {
+        return None;
+    }
+    let seq_len = data.get_u16() as usize;
+    if data.len() < seq_len + 3 {
+        return None;
+    }
+    let seq = data.split_to(seq_len);
+    let flags = data.get_u8();
+    let count = data.get_u16();
+    let _ = writeln!(out, "seq {seq:02x?} flags {flags:#04x} parts {count}");
+
+    for i in 0..count {
+        if data.len() < 2 {
+            break;
+        }
+        let size = (data.get_u16() as usize).min(data.len());
+        let part = data.split_to(size);
+        // only the first part of a message is the header, continuation
+        // frames are printed raw
+        match Header::parse_from_bytes(&part) {
+            Ok(header) if i == 0 && header.has_uri() => {
+                let _ = write!(out, "{}", text_format::print_to_string_pretty(&header));
+            }
+            _ => {
+                let _ = writeln!(out, "part {i}: {} bytes", part.len());
+            }
+        }
+    }
+    Some(out)
+}
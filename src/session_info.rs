@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use log::{debug, trace};
+use tokio::sync::watch;
+use xmltree::{Element, XMLNode};
+
+use crate::consts::PacketType;
+use crate::dispatch::PacketHandler;
+use crate::error::{Error, Result};
+use crate::packet::{Packet, PacketPayload};
+
+// ProductInfo children, e.g. type=premium, catalogue=premium, filter-explicit-content=0
+pub type UserAttributes = HashMap<String, String>;
+
+// Everything the AP pushes about the account right after login. Fields stay
+// empty until the corresponding packet has arrived.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionInfo {
+    pub country: Option<String>,
+    // Some once ProductInfo has arrived, even if it carried no attributes
+    pub attributes: Option<UserAttributes>,
+    pub license_version: Option<u16>,
+    pub license_name: Option<String>,
+    pub preferred_locale: Option<String>,
+}
+
+impl SessionInfo {
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.as_ref()?.get(key).map(String::as_str)
+    }
+
+    // None until ProductInfo has been received
+    pub fn account_type(&self) -> Option<&str> {
+        self.attribute("type")
+    }
+
+    pub fn is_premium(&self) -> bool {
+        self.account_type() == Some("premium")
+    }
+
+    pub fn catalogue(&self) -> Option<&str> {
+        self.attribute("catalogue")
+    }
+
+    pub fn filter_explicit_content(&self) -> bool {
+        self.attribute("filter-explicit-content") == Some("1")
+    }
+}
+
+pub struct SessionInfoHandler {
+    tx: watch::Sender<SessionInfo>,
+}
+
+impl SessionInfoHandler {
+    pub fn new() -> (SessionInfoHandler, watch::Receiver<SessionInfo>) {
+        let (tx, rx) = watch::channel(SessionInfo::default());
+        (SessionInfoHandler { tx }, rx)
+    }
+
+    fn update(&self, cmd: PacketType, data: Bytes) -> Result<()> {
+        if cmd == PacketType::ProductInfo {
+            let attributes = parse_product_info(&data)?;
+            debug!("received {} user attributes", attributes.len());
+            self.tx.send_if_modified(|info| replace(&mut info.attributes, Some(attributes)));
+            return Ok(());
+        }
+
+        let payload = match Packet::new(cmd, data).decode_payload()? {
+            Some(payload) => payload,
+            None => return Ok(()),
+        };
+        self.tx.send_if_modified(|info| match payload {
+            PacketPayload::CountryCode(country) => {
+                debug!("country code {country}");
+                replace(&mut info.country, Some(country))
+            }
+            PacketPayload::LicenseVersion { id, name } => {
+                let changed = info.license_version != Some(id) || info.license_name != name;
+                info.license_version = Some(id);
+                info.license_name = name;
+                changed
+            }
+            PacketPayload::PreferredLocale { key, value } if key == "preferred-locale" => {
+                replace(&mut info.preferred_locale, Some(value))
+            }
+            other => {
+                trace!("ignoring {other:?}");
+                false
+            }
+        });
+        Ok(())
+    }
+}
+
+impl PacketHandler for SessionInfoHandler {
+    fn handle(&self, cmd: PacketType, data: Bytes) -> Result<()> {
+        self.update(cmd, data)
+    }
+}
+
+fn replace<T: PartialEq>(slot: &mut T, value: T) -> bool {
+    if *slot == value {
+        return false;
+    }
+    *slot = value;
+    true
+}
+
+// <products><product><type>premium</type>...</product></products>
+fn parse_product_info(data: &[u8]) -> Result<UserAttributes> {
+    let malformed = || Error::MalformedPacket(PacketType::ProductInfo as u8);
+    let root = Element::parse(data).map_err(|_| malformed())?;
+    let product = root.get_child("product").ok_or_else(malformed)?;
+
+    Ok(product
+        .children
+        .iter()
+        .filter_map(XMLNode::as_element)
+        .map(|e| {
+            let value = e.get_text().map(|t| t.into_owned()).unwrap_or_default();
+            (e.name.clone(), value)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRODUCT_INFO: &[u8] = br#"<?xml version="1.0" encoding="utf-8" ?>
+<products>
+  <product>
+    <type>premium</type>
+    <catalogue>premium</catalogue>
+    <filter-explicit-content>1</filter-explicit-content>
+    <head-files-url>https://heads-fa.spotify.com/head/{file_id}</head-files-url>
+    <ads/>
+  </product>
+</products>"#;
+
+    #[test]
+    fn product_info() {
+        let attributes = parse_product_info(PRODUCT_INFO).unwrap();
+        let info = SessionInfo {
+            attributes: Some(attributes),
+            ..SessionInfo::default()
+        };
+        assert!(info.is_premium());
+        assert_eq!(info.catalogue(), Some("premium"));
+        assert!(info.filter_explicit_content());
+        assert_eq!(info.attribute("head-files-url"), Some("https://heads-fa.spotify.com/head/{file_id}"));
+        assert_eq!(info.attribute("ads"), Some(""));
+
+        assert!(parse_product_info(b"<products/>").is_err());
+        assert!(parse_product_info(b"<products><product>").is_err());
+    }
+
+    #[test]
+    fn changes_are_broadcast() {
+        let (handler, mut info) = SessionInfoHandler::new();
+        assert_eq!(info.borrow().account_type(), None);
+
+        handler.handle(PacketType::ProductInfo, Bytes::from_static(PRODUCT_INFO)).unwrap();
+        assert!(info.has_changed().unwrap());
+        assert_eq!(info.borrow_and_update().account_type(), Some("premium"));
+
+        // the same attributes again are no change
+        handler.handle(PacketType::ProductInfo, Bytes::from_static(PRODUCT_INFO)).unwrap();
+        assert!(!info.has_changed().unwrap());
+        handler.handle(PacketType::CountryCode, Bytes::from_static(b"SE")).unwrap();
+        assert!(info.has_changed().unwrap());
+        assert_eq!(info.borrow_and_update().country.as_deref(), Some("SE"));
+        handler.handle(PacketType::CountryCode, Bytes::from_static(b"SE")).unwrap();
+        assert!(!info.has_changed().unwrap());
+
+        // an empty product still counts as received
+        let empty = Bytes::from_static(b"<products><product/></products>");
+        handler.handle(PacketType::ProductInfo, empty).unwrap();
+        assert_eq!(info.borrow_and_update().attributes, Some(UserAttributes::new()));
+    }
+}
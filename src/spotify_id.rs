@@ -0,0 +1,425 @@
+use std::fmt;
+use std::str::FromStr;
+
+use reqwest::Url;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+const BASE62_DIGITS: &[u8; 62] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const BASE16_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+pub const SPOTIFY_ID_SIZE: usize = 16;
+pub const FILE_ID_SIZE: usize = 20;
+
+const URI_PREFIX: &str = "spotify";
+const URL_HOST: &str = "open.spotify.com";
+
+#[derive(Debug, Error)]
+pub enum SpotifyIdError {
+    #[error("invalid id {0:?}")]
+    InvalidId(String),
+    #[error("invalid spotify uri {0:?}")]
+    InvalidUri(String),
+    #[error("invalid spotify url {0:?}")]
+    InvalidUrl(String),
+    #[error("unknown item type {0:?}")]
+    UnknownItemType(String),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SpotifyItemType {
+    Track,
+    Episode,
+    Album,
+    Artist,
+    Playlist,
+    Show,
+    Local,
+    User,
+}
+
+impl SpotifyItemType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SpotifyItemType::Track => "track",
+            SpotifyItemType::Episode => "episode",
+            SpotifyItemType::Album => "album",
+            SpotifyItemType::Artist => "artist",
+            SpotifyItemType::Playlist => "playlist",
+            SpotifyItemType::Show => "show",
+            SpotifyItemType::Local => "local",
+            SpotifyItemType::User => "user",
+        }
+    }
+
+    // user names and local file descriptions are not base62 ids
+    pub fn has_gid(&self) -> bool {
+        !matches!(self, SpotifyItemType::Local | SpotifyItemType::User)
+    }
+}
+
+impl FromStr for SpotifyItemType {
+    type Err = SpotifyIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "track" => SpotifyItemType::Track,
+            "episode" => SpotifyItemType::Episode,
+            "album" => SpotifyItemType::Album,
+            "artist" => SpotifyItemType::Artist,
+            "playlist" => SpotifyItemType::Playlist,
+            "show" => SpotifyItemType::Show,
+            "local" => SpotifyItemType::Local,
+            "user" => SpotifyItemType::User,
+            other => return Err(SpotifyIdError::UnknownItemType(other.to_owned())),
+        })
+    }
+}
+
+impl fmt::Display for SpotifyItemType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// An item such as `spotify:track:6rqhFgbbKwnb9MLmUQDhG6`. Items with a GID
+// carry it as a 128 bit number; user and local items carry their name
+// (the user name, or the `artist:album:title:duration` part of a local URI).
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SpotifyId {
+    pub item_type: SpotifyItemType,
+    pub id: u128,
+    name: Option<String>,
+}
+
+impl SpotifyId {
+    pub fn new(item_type: SpotifyItemType, id: u128) -> SpotifyId {
+        SpotifyId {
+            item_type,
+            id,
+            name: None,
+        }
+    }
+
+    pub fn user(name: impl Into<String>) -> SpotifyId {
+        SpotifyId {
+            item_type: SpotifyItemType::User,
+            id: 0,
+            name: Some(name.into()),
+        }
+    }
+
+    pub fn local(name: impl Into<String>) -> SpotifyId {
+        SpotifyId {
+            item_type: SpotifyItemType::Local,
+            id: 0,
+            name: Some(name.into()),
+        }
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn from_base62(item_type: SpotifyItemType, s: &str) -> Result<SpotifyId, SpotifyIdError> {
+        let invalid = || SpotifyIdError::InvalidId(s.to_owned());
+        if s.len() != 22 {
+            return Err(invalid());
+        }
+
+        let mut id: u128 = 0;
+        for c in s.bytes() {
+            let digit = BASE62_DIGITS.iter().position(|d| *d == c).ok_or_else(invalid)?;
+            id = id
+                .checked_mul(62)
+                .and_then(|id| id.checked_add(digit as u128))
+                .ok_or_else(invalid)?;
+        }
+        Ok(SpotifyId::new(item_type, id))
+    }
+
+    pub fn from_base16(item_type: SpotifyItemType, s: &str) -> Result<SpotifyId, SpotifyIdError> {
+        let invalid = || SpotifyIdError::InvalidId(s.to_owned());
+        if s.len() != SPOTIFY_ID_SIZE * 2 {
+            return Err(invalid());
+        }
+
+        // from_str_radix would also take a leading sign
+        let mut id: u128 = 0;
+        for c in s.bytes() {
+            id = id << 4 | hex_digit(c).ok_or_else(invalid)? as u128;
+        }
+        Ok(SpotifyId::new(item_type, id))
+    }
+
+    pub fn from_raw(item_type: SpotifyItemType, data: &[u8]) -> Result<SpotifyId, SpotifyIdError> {
+        let raw: [u8; SPOTIFY_ID_SIZE] = data
+            .try_into()
+            .map_err(|_| SpotifyIdError::InvalidId(format!("{data:02x?}")))?;
+        Ok(SpotifyId::new(item_type, u128::from_be_bytes(raw)))
+    }
+
+    // Accepts `spotify:<type>:<id>`, the legacy
+    // `spotify:user:<name>:playlist:<id>` and `spotify:local:...` forms.
+    pub fn from_uri(uri: &str) -> Result<SpotifyId, SpotifyIdError> {
+        let invalid = || SpotifyIdError::InvalidUri(uri.to_owned());
+
+        let rest = uri
+            .strip_prefix(URI_PREFIX)
+            .and_then(|rest| rest.strip_prefix(':'))
+            .ok_or_else(invalid)?;
+        let (item_type, id) = rest.split_once(':').ok_or_else(invalid)?;
+        let item_type: SpotifyItemType = item_type.parse()?;
+
+        match item_type {
+            SpotifyItemType::Local if !id.is_empty() => Ok(SpotifyId::local(id)),
+            SpotifyItemType::User => match id.split_once(':') {
+                Some((_, playlist)) => SpotifyId::from_uri(&format!("{URI_PREFIX}:{playlist}")),
+                None if !id.is_empty() => Ok(SpotifyId::user(id)),
+                None => Err(invalid()),
+            },
+            _ if item_type.has_gid() => SpotifyId::from_base62(item_type, id),
+            _ => Err(invalid()),
+        }
+    }
+
+    // Accepts `https://open.spotify.com/<type>/<id>`, with or without a
+    // locale segment (`/intl-de/`) and query string.
+    pub fn from_url(url: &str) -> Result<SpotifyId, SpotifyIdError> {
+        let invalid = || SpotifyIdError::InvalidUrl(url.to_owned());
+
+        let parsed = Url::parse(url).map_err(|_| invalid())?;
+        if !matches!(parsed.scheme(), "http" | "https") || parsed.host_str() != Some(URL_HOST) {
+            return Err(invalid());
+        }
+
+        let mut segments = parsed
+            .path_segments()
+            .ok_or_else(invalid)?
+            .filter(|segment| !segment.is_empty() && !segment.starts_with("intl-"));
+        let item_type: SpotifyItemType = segments.next().ok_or_else(invalid)?.parse()?;
+        let id = segments.next().ok_or_else(invalid)?;
+
+        match item_type {
+            SpotifyItemType::User => match (segments.next(), segments.next()) {
+                (Some("playlist"), Some(playlist)) => {
+                    SpotifyId::from_base62(SpotifyItemType::Playlist, playlist)
+                }
+                (None, _) => Ok(SpotifyId::user(id)),
+                _ => Err(invalid()),
+            },
+            SpotifyItemType::Local => Err(invalid()),
+            _ => SpotifyId::from_base62(item_type, id),
+        }
+    }
+
+    pub fn to_base62(&self) -> String {
+        let mut digits = [0u8; 22];
+        let mut id = self.id;
+        for digit in digits.iter_mut().rev() {
+            *digit = BASE62_DIGITS[(id % 62) as usize];
+            id /= 62;
+        }
+        String::from_utf8(digits.to_vec()).expect("base62 digits are ASCII")
+    }
+
+    pub fn to_base16(&self) -> String {
+        format!("{:032x}", self.id)
+    }
+
+    pub fn to_raw(&self) -> [u8; SPOTIFY_ID_SIZE] {
+        self.id.to_be_bytes()
+    }
+
+    pub fn to_uri(&self) -> String {
+        match &self.name {
+            Some(name) => format!("{URI_PREFIX}:{}:{name}", self.item_type),
+            None => format!("{URI_PREFIX}:{}:{}", self.item_type, self.to_base62()),
+        }
+    }
+
+    // None for local items, which have no web page
+    pub fn to_url(&self) -> Option<String> {
+        match (self.item_type, &self.name) {
+            (SpotifyItemType::Local, _) => None,
+            (_, Some(name)) => Some(format!("https://{URL_HOST}/{}/{name}", self.item_type)),
+            (_, None) => Some(format!("https://{URL_HOST}/{}/{}", self.item_type, self.to_base62())),
+        }
+    }
+}
+
+impl FromStr for SpotifyId {
+    type Err = SpotifyIdError;
+
+    // URIs and open.spotify.com URLs
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("http://") || s.starts_with("https://") {
+            SpotifyId::from_url(s)
+        } else {
+            SpotifyId::from_uri(s)
+        }
+    }
+}
+
+impl fmt::Display for SpotifyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_uri())
+    }
+}
+
+impl fmt::Debug for SpotifyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SpotifyId({self})")
+    }
+}
+
+impl Serialize for SpotifyId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_uri())
+    }
+}
+
+impl<'de> Deserialize<'de> for SpotifyId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+// 160 bit id of an audio file, cover image or other blob, shown as 40 hex digits
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FileId(pub [u8; FILE_ID_SIZE]);
+
+impl FileId {
+    pub fn from_raw(data: &[u8]) -> Option<FileId> {
+        data.try_into().ok().map(FileId)
+    }
+
+    pub fn to_base16(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl FromStr for FileId {
+    type Err = SpotifyIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SpotifyIdError::InvalidId(s.to_owned());
+        if s.len() != FILE_ID_SIZE * 2 || !s.is_ascii() {
+            return Err(invalid());
+        }
+
+        let mut id = [0u8; FILE_ID_SIZE];
+        for (byte, pair) in id.iter_mut().zip(s.as_bytes().chunks(2)) {
+            let hi = hex_digit(pair[0]).ok_or_else(invalid)?;
+            let lo = hex_digit(pair[1]).ok_or_else(invalid)?;
+            *byte = hi << 4 | lo;
+        }
+        Ok(FileId(id))
+    }
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    BASE16_DIGITS
+        .iter()
+        .position(|d| *d == c.to_ascii_lowercase())
+        .map(|d| d as u8)
+}
+
+impl fmt::Display for FileId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for FileId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FileId({self})")
+    }
+}
+
+impl Serialize for FileId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FileId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: u128 = 238762092608182713602505436543891614649;
+    const BASE62: &str = "5sWHDYs0csV6RS48xBl0tH";
+    const BASE16: &str = "b39fe8081e1f4c54be38e8d6f9f12bb9";
+
+    #[test]
+    fn round_trips() {
+        let id = SpotifyId::new(SpotifyItemType::Track, ID);
+        assert_eq!(id.to_base62(), BASE62);
+        assert_eq!(id.to_base16(), BASE16);
+        assert_eq!(SpotifyId::from_base62(SpotifyItemType::Track, BASE62).unwrap(), id);
+        assert_eq!(SpotifyId::from_base16(SpotifyItemType::Track, BASE16).unwrap(), id);
+        assert_eq!(SpotifyId::from_raw(SpotifyItemType::Track, &id.to_raw()).unwrap(), id);
+        assert_eq!(SpotifyId::from_uri(&id.to_uri()).unwrap(), id);
+        assert_eq!(SpotifyId::from_url(&id.to_url().unwrap()).unwrap(), id);
+
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"spotify:track:{BASE62}\""));
+        assert_eq!(serde_json::from_str::<SpotifyId>(&json).unwrap(), id);
+    }
+
+    #[test]
+    fn parses_uri_and_url_forms() {
+        let playlist = SpotifyId::new(SpotifyItemType::Playlist, ID);
+        assert_eq!(format!("spotify:user:alice:playlist:{BASE62}").parse::<SpotifyId>().unwrap(), playlist);
+        assert_eq!(
+            format!("https://open.spotify.com/intl-de/playlist/{BASE62}?si=abc").parse::<SpotifyId>().unwrap(),
+            playlist
+        );
+        assert_eq!("spotify:user:alice".parse::<SpotifyId>().unwrap(), SpotifyId::user("alice"));
+
+        let local = "spotify:local:Artist:Album:Title:123".parse::<SpotifyId>().unwrap();
+        assert_eq!(local.name(), Some("Artist:Album:Title:123"));
+        assert_eq!(local.to_uri(), "spotify:local:Artist:Album:Title:123");
+        assert_eq!(local.to_url(), None);
+    }
+
+    #[test]
+    fn rejects_invalid_ids() {
+        let track = SpotifyItemType::Track;
+        assert!(SpotifyId::from_base62(track, "short").is_err());
+        assert!(SpotifyId::from_base62(track, "5sWHDYs0csV6RS48xBl0t-").is_err());
+        // larger than 128 bits
+        assert!(SpotifyId::from_base62(track, "zzzzzzzzzzzzzzzzzzzzzz").is_err());
+        assert!(SpotifyId::from_base16(track, "+0000000000000000000000000000001").is_err());
+        assert!(SpotifyId::from_base16(track, "b39fe8081e1f4c54be38e8d6f9f12bbg").is_err());
+        assert!(SpotifyId::from_raw(track, &[0; 15]).is_err());
+
+        assert!(matches!("spotify:foo:bar".parse::<SpotifyId>(), Err(SpotifyIdError::UnknownItemType(_))));
+        assert!(matches!("spotify:track".parse::<SpotifyId>(), Err(SpotifyIdError::InvalidUri(_))));
+        assert!(matches!(
+            format!("https://example.com/track/{BASE62}").parse::<SpotifyId>(),
+            Err(SpotifyIdError::InvalidUrl(_))
+        ));
+    }
+
+    #[test]
+    fn file_id() {
+        let hex = "0123456789abcdef0123456789abcdef01234567";
+        let file: FileId = hex.to_uppercase().parse().unwrap();
+        assert_eq!(file.to_base16(), hex);
+        assert_eq!(FileId::from_raw(&file.0), Some(file));
+        assert!("+123456789abcdef0123456789abcdef01234567".parse::<FileId>().is_err());
+        assert!("0123".parse::<FileId>().is_err());
+    }
+}
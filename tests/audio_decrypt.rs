@@ -0,0 +1,52 @@
+use std::io::{Cursor, ErrorKind, SeekFrom};
+
+use aes::Aes128;
+use ctr::Ctr128BE;
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+use fyspoti::audio_decrypt::SPOTIFY_HEADER_SIZE;
+use fyspoti::{AudioDecrypt, AudioKey};
+
+const KEY: [u8; 16] = *b"0123456789abcdef";
+// the IV every audio file is encrypted with
+const IV: [u8; 16] = [
+    0x72, 0xe0, 0x67, 0xfb, 0xdd, 0xcb, 0xcf, 0x77, 0xeb, 0xe8, 0xbc, 0x64, 0x3f, 0x63, 0x0d, 0x93,
+];
+
+// a whole file, header included, encrypted from offset 0
+fn encrypted_file(plain: &[u8]) -> Cursor<Vec<u8>> {
+    let mut data = plain.to_vec();
+    Ctr128BE::<Aes128>::new(&KEY.into(), &IV.into()).apply_keystream(&mut data);
+    Cursor::new(data)
+}
+
+#[tokio::test]
+async fn decrypts_after_header() {
+    let plain: Vec<u8> = (0..0x400u32).map(|i| (i * 7 % 251) as u8).collect();
+    let mut decrypt = AudioDecrypt::new(AudioKey(KEY), encrypted_file(&plain)).await.unwrap();
+
+    let ogg = &plain[SPOTIFY_HEADER_SIZE as usize..];
+    let mut data = Vec::new();
+    decrypt.read_to_end(&mut data).await.unwrap();
+    assert_eq!(data, ogg);
+
+    // keystream follows seeks, also to offsets inside an AES block
+    for pos in [0x100, 0x13, 0] {
+        assert_eq!(decrypt.seek(SeekFrom::Start(pos)).await.unwrap(), pos);
+        let mut data = [0u8; 0x40];
+        decrypt.read_exact(&mut data).await.unwrap();
+        assert_eq!(data, ogg[pos as usize..][..0x40]);
+    }
+}
+
+#[tokio::test]
+async fn seek_past_u64_is_refused() {
+    let file = Cursor::new(vec![0u8; 0x200]);
+    let mut decrypt = AudioDecrypt::new(AudioKey([0; 16]), file).await.unwrap();
+
+    let error = decrypt.seek(SeekFrom::Start(u64::MAX)).await.unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    // the reader is still usable afterwards
+    assert_eq!(decrypt.seek(SeekFrom::Start(0x10)).await.unwrap(), 0x10);
+}
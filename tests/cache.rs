@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+
+use fyspoti::protocol::authentication::AuthenticationType;
+use fyspoti::{Credentials, CredentialsCache};
+
+// a fresh directory per test, the cache itself creates the last component
+fn cache_dir(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("fyspoti-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    root.join("cache")
+}
+
+fn stored_credentials() -> Credentials {
+    Credentials {
+        username: Some("alice".to_owned()),
+        auth_type: AuthenticationType::AUTHENTICATION_STORED_SPOTIFY_CREDENTIALS,
+        auth_data: b"reusable".to_vec(),
+    }
+}
+
+#[test]
+fn save_and_load() {
+    let dir = cache_dir("save-and-load");
+    let cache = CredentialsCache::new(&dir);
+
+    assert_eq!(cache.load("alice").unwrap(), None);
+    cache.save(&stored_credentials()).unwrap();
+    assert_eq!(cache.load("alice").unwrap(), Some(stored_credentials()));
+
+    cache.remove("alice").unwrap();
+    assert_eq!(cache.load("alice").unwrap(), None);
+    std::fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn save_as_login_name() {
+    let dir = cache_dir("save-as");
+    let cache = CredentialsCache::new(&dir);
+
+    cache.save_as("alice@example.com", &stored_credentials()).unwrap();
+    // found under the login name, but still carries the canonical username
+    assert_eq!(cache.load("alice@example.com").unwrap(), Some(stored_credentials()));
+    assert_eq!(cache.load("alice").unwrap(), None);
+    std::fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn private_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = cache_dir("permissions");
+    let cache = CredentialsCache::new(&dir);
+    cache.save(&stored_credentials()).unwrap();
+
+    let mode = |path: PathBuf| std::fs::metadata(path).unwrap().permissions().mode() & 0o777;
+    assert_eq!(mode(dir.clone()), 0o700);
+    let file = std::fs::read_dir(&dir).unwrap().next().unwrap().unwrap().path();
+    assert_eq!(mode(file), 0o600);
+    std::fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+}
@@ -0,0 +1,28 @@
+use tokio::io::AsyncWriteExt;
+
+use fyspoti::mock::test_handshake_config;
+use fyspoti::{Error, HandshakeError, handshake::handshake_with_config};
+
+// an AP that answers the hello with nothing but a size header
+async fn handshake_with_size(size: u32) -> Error {
+    let (client, mut server) = tokio::io::duplex(1 << 16);
+    tokio::spawn(async move {
+        server.write_all(&size.to_be_bytes()).await.unwrap();
+        // keep the stream open so only the size is judged
+        tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+    });
+
+    handshake_with_config(client, &test_handshake_config()).await.err().unwrap()
+}
+
+#[tokio::test]
+async fn size_below_header() {
+    let error = handshake_with_size(2).await;
+    assert!(matches!(error, Error::Handshake(HandshakeError::InvalidPacketSize(2))));
+}
+
+#[tokio::test]
+async fn size_too_large() {
+    let error = handshake_with_size(u32::MAX).await;
+    assert!(matches!(error, Error::Handshake(HandshakeError::InvalidPacketSize(_))));
+}
@@ -0,0 +1,246 @@
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use tokio::io::DuplexStream;
+use tokio::task::JoinHandle;
+use tokio_util::codec::Framed;
+
+use fyspoti::mock::{MockAccessPoint, MockLogin, MockRejection, test_handshake_config};
+use fyspoti::protocol::keyexchange::{Cryptosuite, ErrorCode};
+use fyspoti::{
+    ApCodec, Channel, ChannelError, CodecError, Credentials, Error, FileId, HandshakeConfig, HandshakeError, Packet,
+    PacketType, Result, Session, SessionConfig,
+};
+
+type Server = JoinHandle<Result<Framed<DuplexStream, ApCodec>>>;
+
+async fn connect(ap: MockAccessPoint, handshake: HandshakeConfig) -> (Result<Session>, Server) {
+    let config = SessionConfig {
+        handshake,
+        ..SessionConfig::default()
+    };
+    connect_with_config(ap, config).await
+}
+
+async fn connect_with_config(ap: MockAccessPoint, config: SessionConfig) -> (Result<Session>, Server) {
+    let (client, server) = tokio::io::duplex(1 << 16);
+    let server = tokio::spawn(async move { ap.serve(server).await });
+
+    let session = Session::connect_with(client, config, Credentials::with_password("alice", "secret")).await;
+    (session, server)
+}
+
+#[tokio::test]
+async fn login_welcome() {
+    let ap = MockAccessPoint::new().with_login(MockLogin::welcome("alice"));
+    let (session, server) = connect(ap, test_handshake_config()).await;
+
+    let session = session.unwrap();
+    assert_eq!(session.username(), "alice");
+    assert_eq!(session.welcome().reusable_credentials.auth_data, b"mock-credentials");
+    assert!(!session.is_closed());
+    drop(server);
+}
+
+#[tokio::test]
+async fn login_failure() {
+    let ap = MockAccessPoint::new().with_login(MockLogin::failure(ErrorCode::BadCredentials));
+    let (session, _server) = connect(ap, test_handshake_config()).await;
+
+    match session {
+        Err(Error::LoginFailed(failure)) => assert_eq!(failure.error_code, ErrorCode::BadCredentials),
+        other => panic!("expected a login failure, got {:?}", other.err()),
+    }
+}
+
+#[tokio::test]
+async fn proof_of_work() {
+    let ap = MockAccessPoint::new().with_pow(8);
+    let (session, _server) = connect(ap, test_handshake_config()).await;
+
+    session.unwrap();
+}
+
+#[tokio::test]
+async fn rejected_with_upgrade() {
+    let ap = MockAccessPoint::new().with_rejection(MockRejection::Upgrade {
+        http_suffix: "client-upgrade".to_owned(),
+    });
+    let (session, _server) = connect(ap, test_handshake_config()).await;
+
+    match session {
+        Err(Error::Handshake(HandshakeError::UpgradeRequired { http_suffix, .. })) => {
+            assert_eq!(http_suffix.as_deref(), Some("client-upgrade"))
+        }
+        other => panic!("expected an upgrade, got {:?}", other.err()),
+    }
+}
+
+#[tokio::test]
+async fn rejected_with_login_failed() {
+    let ap = MockAccessPoint::new().with_rejection(MockRejection::LoginFailed(ErrorCode::TryAnotherAP));
+    let (session, _server) = connect(ap, test_handshake_config()).await;
+
+    match session {
+        Err(Error::LoginFailed(failure)) => assert_eq!(failure.error_code, ErrorCode::TryAnotherAP),
+        other => panic!("expected a login failure, got {:?}", other.err()),
+    }
+}
+
+#[tokio::test]
+async fn rc4_cryptosuite() {
+    let ap = MockAccessPoint::new().with_cryptosuite(Cryptosuite::CRYPTO_SUITE_RC4_SHA1_HMAC);
+    let config = test_handshake_config().cryptosuites(vec![
+        Cryptosuite::CRYPTO_SUITE_SHANNON,
+        Cryptosuite::CRYPTO_SUITE_RC4_SHA1_HMAC,
+    ]);
+    let (session, server) = connect(ap, config).await;
+
+    let session = session.unwrap();
+    // the first packet after login must decrypt on the server side
+    session.send_packet(PacketType::Pong, vec![0, 0, 0, 0]).unwrap();
+    let mut transport = server.await.unwrap().unwrap();
+    let packet = transport.next().await.unwrap().unwrap();
+    assert_eq!(packet.kind(), PacketType::Pong);
+}
+
+#[tokio::test]
+async fn cryptosuite_not_offered() {
+    let ap = MockAccessPoint::new().with_cryptosuite(Cryptosuite::CRYPTO_SUITE_RC4_SHA1_HMAC);
+    let (session, _server) = connect(ap, test_handshake_config()).await;
+
+    match session {
+        Err(Error::Handshake(HandshakeError::UnsupportedCryptosuite(suite))) => {
+            assert_eq!(suite, Cryptosuite::CRYPTO_SUITE_RC4_SHA1_HMAC)
+        }
+        other => panic!("expected an unsupported cryptosuite, got {:?}", other.err()),
+    }
+}
+
+#[tokio::test]
+async fn connection_lost() {
+    let (session, server) = connect(MockAccessPoint::new(), test_handshake_config()).await;
+
+    let session = session.unwrap();
+    drop(server.await.unwrap().unwrap());
+    assert!(matches!(session.wait().await, Err(Error::ConnectionLost)));
+    // every clone sees the same end, not just the first caller
+    assert!(matches!(session.clone().wait().await, Err(Error::ConnectionLost)));
+}
+
+#[tokio::test]
+async fn oversized_packet() {
+    let (session, _server) = connect(MockAccessPoint::new(), test_handshake_config()).await;
+
+    let session = session.unwrap();
+    let result = session.send_packet(PacketType::MercuryReq, vec![0; 70000]);
+    assert!(matches!(result, Err(Error::Codec(CodecError::FrameTooLarge(70000)))));
+    assert!(!session.is_closed());
+}
+
+// a channel request as the AP sees it, with the channel id it was sent on
+async fn open_channel(session: &Session, server: Server) -> (Channel, [u8; 2], Framed<DuplexStream, ApCodec>) {
+    let channel = session.channels().request(FileId([7; 20]), 0, 1024).unwrap();
+    let mut transport = server.await.unwrap().unwrap();
+    let request = transport.next().await.unwrap().unwrap();
+    assert_eq!(request.kind(), PacketType::StreamChunk);
+    let id = [request.payload[0], request.payload[1]];
+    (channel, id, transport)
+}
+
+#[tokio::test]
+async fn channel_fails_on_connection_loss() {
+    let (session, server) = connect(MockAccessPoint::new(), test_handshake_config()).await;
+
+    let session = session.unwrap();
+    let (mut channel, _, transport) = open_channel(&session, server).await;
+    drop(transport);
+
+    let result = tokio::time::timeout(Duration::from_secs(5), channel.next()).await.unwrap();
+    assert!(matches!(result, Some(Err(Error::SessionClosed))));
+    assert!(channel.next().await.is_none());
+}
+
+#[tokio::test]
+async fn channel_headers_and_data() {
+    let (session, server) = connect(MockAccessPoint::new(), test_handshake_config()).await;
+    let session = session.unwrap();
+    let (mut channel, id, mut transport) = open_channel(&session, server).await;
+
+    let frames: [&[u8]; 5] = [
+        // two header entries: len, id, data
+        b"\x00\x03\x03\x00\x20\x00\x02\x01\xff",
+        // the header section ends with an empty entry
+        b"\x00\x00",
+        b"first",
+        b"second",
+        // an empty chunk ends the channel
+        b"",
+    ];
+    for frame in frames {
+        let payload = [&id[..], frame].concat();
+        transport.send(Packet::new(PacketType::StreamChunkRes, payload)).await.unwrap();
+    }
+
+    assert_eq!(channel.next().await.unwrap().unwrap(), &b"first"[..]);
+    assert_eq!(channel.headers().len(), 2);
+    assert_eq!(channel.header(0x03).unwrap(), &b"\x00\x20"[..]);
+    assert_eq!(channel.header(0x01).unwrap(), &b"\xff"[..]);
+    assert_eq!(channel.next().await.unwrap().unwrap(), &b"second"[..]);
+    assert!(channel.next().await.is_none());
+}
+
+#[tokio::test]
+async fn channel_error_code() {
+    let (session, server) = connect(MockAccessPoint::new(), test_handshake_config()).await;
+    let session = session.unwrap();
+    let (mut channel, id, mut transport) = open_channel(&session, server).await;
+
+    let payload = [&id[..], b"\x00\x02"].concat();
+    transport.send(Packet::new(PacketType::ChannelError, payload)).await.unwrap();
+
+    let result = channel.next().await.unwrap();
+    assert!(matches!(result, Err(Error::Channel(ChannelError::Remote(2)))));
+    assert!(channel.next().await.is_none());
+}
+
+#[tokio::test]
+async fn empty_product_info() {
+    let (session, server) = connect(MockAccessPoint::new(), test_handshake_config()).await;
+
+    let session = session.unwrap();
+    let mut transport = server.await.unwrap().unwrap();
+    let product_info = Packet::new(PacketType::ProductInfo, &b"<products><product/></products>"[..]);
+    transport.send(product_info).await.unwrap();
+
+    let info = tokio::time::timeout(Duration::from_secs(5), session.wait_for_attributes()).await.unwrap();
+    let info = info.unwrap();
+    assert!(info.attributes.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn ping_is_answered_with_pong() {
+    let (session, server) = connect(MockAccessPoint::new(), test_handshake_config()).await;
+
+    let _session = session.unwrap();
+    let mut transport = server.await.unwrap().unwrap();
+    transport.send(Packet::new(PacketType::Ping, vec![0, 0, 0, 42])).await.unwrap();
+    let pong = transport.next().await.unwrap().unwrap();
+    assert_eq!(pong.kind(), PacketType::Pong);
+}
+
+#[tokio::test]
+async fn ping_timeout_loses_connection() {
+    let config = SessionConfig {
+        handshake: test_handshake_config(),
+        ping_timeout: Duration::from_millis(100),
+        ..SessionConfig::default()
+    };
+    let (session, server) = connect_with_config(MockAccessPoint::new(), config).await;
+
+    let session = session.unwrap();
+    // keep the AP up but silent
+    let _transport = server.await.unwrap().unwrap();
+    let result = tokio::time::timeout(Duration::from_secs(5), session.wait()).await.unwrap();
+    assert!(matches!(result, Err(Error::ConnectionLost)));
+}
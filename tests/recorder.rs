@@ -0,0 +1,75 @@
+use std::io::Write;
+use std::path::PathBuf;
+
+use protobuf::Message;
+
+use fyspoti::protocol::authentication::{
+    APWelcome, AccountType, AuthenticationType, ClientResponseEncrypted, CpuFamily, Os,
+};
+use fyspoti::{Direction, Error, Packet, PacketType, RecordReader, Recorder};
+
+fn recording(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("fyspoti-{name}-{}.rec", std::process::id()))
+}
+
+fn login() -> Packet {
+    let mut login = ClientResponseEncrypted::new();
+    let credentials = login.login_credentials.mut_or_insert_default();
+    credentials.set_username("alice".to_owned());
+    credentials.set_typ(AuthenticationType::AUTHENTICATION_USER_PASS);
+    credentials.set_auth_data(b"secret".to_vec());
+    let system_info = login.system_info.mut_or_insert_default();
+    system_info.set_cpu_family(CpuFamily::CPU_X86_64);
+    system_info.set_os(Os::OS_LINUX);
+    Packet::new(PacketType::Login, login.write_to_bytes().unwrap())
+}
+
+fn welcome() -> Packet {
+    let mut welcome = APWelcome::new();
+    welcome.set_canonical_username("alice".to_owned());
+    welcome.set_account_type_logged_in(AccountType::Spotify);
+    welcome.set_credentials_type_logged_in(AccountType::Spotify);
+    welcome.set_reusable_auth_credentials_type(AuthenticationType::AUTHENTICATION_STORED_SPOTIFY_CREDENTIALS);
+    welcome.set_reusable_auth_credentials(b"reusable".to_vec());
+    Packet::new(PacketType::APWelcome, welcome.write_to_bytes().unwrap())
+}
+
+#[test]
+fn credentials_are_redacted() {
+    let path = recording("redacted");
+    let mut recorder = Recorder::create(&path).unwrap();
+    recorder.record(Direction::Outbound, &login()).unwrap();
+    recorder.record(Direction::Inbound, &welcome()).unwrap();
+    recorder.flush().unwrap();
+    drop(recorder);
+
+    let records: Vec<_> = RecordReader::open(&path).unwrap().map(Result::unwrap).collect();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(records.len(), 2);
+
+    let login = ClientResponseEncrypted::parse_from_bytes(&records[0].packet.payload).unwrap();
+    assert_eq!(records[0].direction, Direction::Outbound);
+    assert_eq!(login.login_credentials.username(), "alice");
+    assert!(login.login_credentials.auth_data().is_empty());
+
+    let welcome = APWelcome::parse_from_bytes(&records[1].packet.payload).unwrap();
+    assert_eq!(records[1].direction, Direction::Inbound);
+    assert_eq!(welcome.canonical_username(), "alice");
+    assert!(welcome.reusable_auth_credentials().is_empty());
+}
+
+#[test]
+fn oversized_record_is_rejected() {
+    let path = recording("oversized");
+    drop(Recorder::create(&path).unwrap());
+    // timestamp, direction, cmd and a length far beyond any frame
+    let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(&[0; 8]).unwrap();
+    file.write_all(&[0, PacketType::MercuryReq as u8]).unwrap();
+    file.write_all(&u32::MAX.to_be_bytes()).unwrap();
+    drop(file);
+
+    let mut records = RecordReader::open(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(records.next(), Some(Err(Error::Recorder(_)))));
+}